@@ -0,0 +1,473 @@
+//! Bit-banged I2C master
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::bitbang::{self, i2c::Speed};
+//! use embedded_hal::blocking::i2c::Write;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::delay::DelayUs;
+//! # use embedded_hal::digital::{InputPin, OutputPin};
+//! # struct Pin;
+//! # impl OutputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl InputPin for Pin {
+//! #     type Error = Infallible;
+//! #     // every byte is acknowledged
+//! #     fn try_is_high(&self) -> Result<bool, Self::Error> { Ok(false) }
+//! #     fn try_is_low(&self) -> Result<bool, Self::Error> { Ok(true) }
+//! # }
+//! # #[derive(Clone, Copy)]
+//! # struct SclPin;
+//! # impl OutputPin for SclPin {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl InputPin for SclPin {
+//! #     type Error = Infallible;
+//! #     fn try_is_high(&self) -> Result<bool, Self::Error> { Ok(true) }
+//! #     fn try_is_low(&self) -> Result<bool, Self::Error> { Ok(false) }
+//! # }
+//! # struct Delay;
+//! # impl DelayUs<u32> for Delay {
+//! #     type Error = Infallible;
+//! #     fn try_delay_us(&mut self, _: u32) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (scl, sda, delay) = (SclPin, Pin, Delay);
+//!
+//! let mut i2c = bitbang::I2c::new(scl, sda, delay, Speed::Standard).unwrap();
+//! i2c.try_write(0x3C, &[0x00, 0xAF]).unwrap();
+//! ```
+
+use crate::blocking::delay::DelayUs;
+use crate::blocking::i2c::{
+    self, general_call, transactional, GeneralCall, NoAcknowledgeSource, Operation, Read,
+    Transactional, Write, WriteIter, WriteRead,
+};
+use crate::digital::{InputPin, OutputPin, PinState};
+use crate::i2c::Configure;
+
+/// Default limit for a slave stretching the clock, in microseconds
+///
+/// This is the SMBus clock low timeout.
+pub const DEFAULT_STRETCH_LIMIT_US: u32 = 25_000;
+
+/// Bus speed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Speed {
+    /// Standard mode, 100 kHz
+    Standard,
+    /// Fast mode, approximately 250 kHz as the half period is rounded up to whole microseconds
+    Fast,
+}
+
+impl Speed {
+    fn half_period_us(self) -> u32 {
+        match self {
+            Speed::Standard => 5,
+            Speed::Fast => 2,
+        }
+    }
+}
+
+wrapper_error! {
+    /// Bit-banged I2C error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Error<PinError, DelayError> {
+        /// Underlying pin error
+        Pin(PinError) => Other, "pin error",
+        /// Underlying delay error
+        Delay(DelayError) => Other, "delay error",
+        /// The slave did not acknowledge a byte
+        NoAcknowledge(NoAcknowledgeSource) => NoAcknowledge(..), "byte not acknowledged",
+        /// The slave held the clock low for longer than the stretch limit
+        ClockStretchTimeout => ClockStretchTimeout, "clock stretched for too long",
+        /// The transaction took longer than the timeout
+        Timeout => Timeout, "transaction timed out",
+        /// The address doesn't fit in 7 bits
+        InvalidAddress(u8) => Other, "invalid address",
+        /// The requested frequency is zero
+        InvalidFrequency => Other, "invalid frequency",
+    }
+
+    impl i2c::Error => i2c::ErrorKind
+}
+
+/// Bit-banged I2C master using open drain clock and data pins
+///
+/// Both pins must be configured as open drain outputs that can be read back, with pull-up
+/// resistors on the bus. Reading SCL back is used to support clock stretching.
+///
+/// The blocking I2C traits are implemented for 7-bit addresses. The frequency can be changed with
+/// [`Configure`], in steps of whole microseconds for the SCL half period. Transactions can be
+/// bounded with [`set_timeout`](I2c::set_timeout).
+pub struct I2c<Scl, Sda, Delay> {
+    scl: Scl,
+    sda: Sda,
+    delay: Delay,
+    half_period_us: u32,
+    stretch_limit_us: u32,
+    timeout_us: Option<u32>,
+    /// Time left for the current transaction, `None` when unbounded
+    remaining_us: Option<u32>,
+}
+
+impl<Scl, Sda, Delay, E> I2c<Scl, Sda, Delay>
+where
+    Scl: OutputPin<Error = E> + InputPin<Error = E>,
+    Sda: OutputPin<Error = E> + InputPin<Error = E>,
+    Delay: DelayUs<u32>,
+{
+    /// Creates a new bus, releasing both lines
+    pub fn new(
+        mut scl: Scl,
+        mut sda: Sda,
+        delay: Delay,
+        speed: Speed,
+    ) -> Result<Self, Error<E, Delay::Error>> {
+        scl.try_set_high().map_err(Error::Pin)?;
+        sda.try_set_high().map_err(Error::Pin)?;
+
+        Ok(I2c {
+            scl,
+            sda,
+            delay,
+            half_period_us: speed.half_period_us(),
+            stretch_limit_us: DEFAULT_STRETCH_LIMIT_US,
+            timeout_us: None,
+            remaining_us: None,
+        })
+    }
+
+    /// Sets how long a slave may stretch the clock before failing with
+    /// [`Error::ClockStretchTimeout`], in microseconds
+    ///
+    /// The limit defaults to [`DEFAULT_STRETCH_LIMIT_US`].
+    pub fn set_stretch_limit(&mut self, us: u32) {
+        self.stretch_limit_us = us;
+    }
+
+    /// Sets how long a transaction may take before failing with [`Error::Timeout`], in
+    /// microseconds, or removes the limit with `None`
+    ///
+    /// The time is counted from the delays between clock edges and while waiting for stretched
+    /// clocks, so transactions take somewhat longer in practice. On a timeout, the bus is
+    /// released with a stop condition. Transactions are not bounded by default, besides the
+    /// [stretch limit](I2c::set_stretch_limit).
+    pub fn set_timeout(&mut self, us: Option<u32>) {
+        self.timeout_us = us;
+    }
+
+    /// Destroys the bus, returning the pins and delay
+    pub fn destroy(self) -> (Scl, Sda, Delay) {
+        (self.scl, self.sda, self.delay)
+    }
+
+    fn wait(&mut self) -> Result<(), Error<E, Delay::Error>> {
+        self.delay_us(self.half_period_us)
+    }
+
+    /// Delays for `us` microseconds, failing if the transaction would exceed its timeout
+    fn delay_us(&mut self, us: u32) -> Result<(), Error<E, Delay::Error>> {
+        if let Some(remaining) = &mut self.remaining_us {
+            *remaining = remaining.checked_sub(us).ok_or(Error::Timeout)?;
+        }
+
+        self.delay.try_delay_us(us).map_err(Error::Delay)
+    }
+
+    /// Runs a transaction within the timeout, releasing the bus if it fails
+    fn transaction(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<(), Error<E, Delay::Error>>,
+    ) -> Result<(), Error<E, Delay::Error>> {
+        self.remaining_us = self.timeout_us;
+        let result = f(self);
+        if result.is_err() {
+            // release the bus even after a timeout, the original error is more relevant than a
+            // failure to do so
+            self.remaining_us = None;
+            let _ = self.stop();
+        }
+
+        result
+    }
+
+    fn sda(&mut self, state: PinState) -> Result<(), Error<E, Delay::Error>> {
+        self.sda.try_set_state(state).map_err(Error::Pin)
+    }
+
+    fn scl_low(&mut self) -> Result<(), Error<E, Delay::Error>> {
+        self.scl.try_set_low().map_err(Error::Pin)
+    }
+
+    /// Releases SCL and waits for slaves to stop stretching the clock
+    fn scl_high(&mut self) -> Result<(), Error<E, Delay::Error>> {
+        self.scl.try_set_high().map_err(Error::Pin)?;
+
+        let mut waited = 0;
+        while self.scl.try_is_low().map_err(Error::Pin)? {
+            if waited == self.stretch_limit_us {
+                return Err(Error::ClockStretchTimeout);
+            }
+            self.delay_us(1)?;
+            waited += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Sends a start condition, or a repeated start if SCL is low
+    fn start(&mut self) -> Result<(), Error<E, Delay::Error>> {
+        self.sda(PinState::High)?;
+        self.wait()?;
+        self.scl_high()?;
+        self.wait()?;
+        self.sda(PinState::Low)?;
+        self.wait()?;
+        self.scl_low()
+    }
+
+    fn stop(&mut self) -> Result<(), Error<E, Delay::Error>> {
+        self.sda(PinState::Low)?;
+        self.wait()?;
+        self.scl_high()?;
+        self.wait()?;
+        self.sda(PinState::High)?;
+        self.wait()
+    }
+
+    fn write_bit(&mut self, bit: bool) -> Result<(), Error<E, Delay::Error>> {
+        self.sda(PinState::from(bit))?;
+        self.wait()?;
+        self.scl_high()?;
+        self.wait()?;
+        self.scl_low()
+    }
+
+    fn read_bit(&mut self) -> Result<bool, Error<E, Delay::Error>> {
+        self.sda(PinState::High)?;
+        self.wait()?;
+        self.scl_high()?;
+        let bit = self.sda.try_is_high().map_err(Error::Pin)?;
+        self.wait()?;
+        self.scl_low()?;
+
+        Ok(bit)
+    }
+
+    /// Writes a byte, returning whether it was acknowledged
+    fn write_byte(&mut self, byte: u8) -> Result<bool, Error<E, Delay::Error>> {
+        for bit in 0..8 {
+            self.write_bit(byte & (0x80 >> bit) != 0)?;
+        }
+
+        Ok(!self.read_bit()?)
+    }
+
+    fn read_byte(&mut self, ack: bool) -> Result<u8, Error<E, Delay::Error>> {
+        let mut byte = 0;
+        for _ in 0..8 {
+            byte = byte << 1 | u8::from(self.read_bit()?);
+        }
+        self.write_bit(!ack)?;
+
+        Ok(byte)
+    }
+
+    fn write_address(&mut self, address: u8, read: bool) -> Result<(), Error<E, Delay::Error>> {
+        if self.write_byte(address << 1 | u8::from(read))? {
+            Ok(())
+        } else {
+            Err(Error::NoAcknowledge(NoAcknowledgeSource::Address))
+        }
+    }
+
+    fn write_data<B>(&mut self, bytes: B) -> Result<(), Error<E, Delay::Error>>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        for byte in bytes {
+            if !self.write_byte(byte)? {
+                return Err(Error::NoAcknowledge(NoAcknowledgeSource::Data));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn exec(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Error<E, Delay::Error>> {
+        let mut reading = None;
+
+        for i in 0..operations.len() {
+            let (op, rest) = operations[i..].split_first_mut().unwrap();
+            let read = matches!(op, Operation::Read(_));
+
+            if reading != Some(read) {
+                self.start()?;
+                self.write_address(address, read)?;
+                reading = Some(read);
+            }
+
+            match op {
+                Operation::Write(bytes) => self.write_data(bytes.iter().copied())?,
+                Operation::Read(buffer) => {
+                    // the last byte read before a (repeated) start or stop is not acknowledged
+                    let last = rest
+                        .iter()
+                        .take_while(|op| matches!(op, Operation::Read(_)))
+                        .all(|op| matches!(op, Operation::Read(b) if b.is_empty()));
+                    let len = buffer.len();
+                    for (j, byte) in buffer.iter_mut().enumerate() {
+                        *byte = self.read_byte(!(last && j == len - 1))?;
+                    }
+                }
+            }
+        }
+
+        if reading.is_some() {
+            self.stop()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Scl, Sda, Delay, E> Transactional for I2c<Scl, Sda, Delay>
+where
+    Scl: OutputPin<Error = E> + InputPin<Error = E>,
+    Sda: OutputPin<Error = E> + InputPin<Error = E>,
+    Delay: DelayUs<u32>,
+{
+    type Error = Error<E, Delay::Error>;
+
+    fn try_exec<'a>(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'a>],
+    ) -> Result<(), Self::Error> {
+        check_address(address)?;
+
+        self.transaction(|i2c| i2c.exec(address, operations))
+    }
+}
+
+impl<Scl, Sda, Delay, E> WriteIter for I2c<Scl, Sda, Delay>
+where
+    Scl: OutputPin<Error = E> + InputPin<Error = E>,
+    Sda: OutputPin<Error = E> + InputPin<Error = E>,
+    Delay: DelayUs<u32>,
+{
+    type Error = Error<E, Delay::Error>;
+
+    fn try_write_iter<B>(&mut self, address: u8, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        check_address(address)?;
+
+        self.transaction(|i2c| {
+            i2c.start()?;
+            i2c.write_address(address, false)?;
+            i2c.write_data(bytes)?;
+            i2c.stop()
+        })
+    }
+}
+
+impl<Scl, Sda, Delay, E> Read for I2c<Scl, Sda, Delay>
+where
+    Scl: OutputPin<Error = E> + InputPin<Error = E>,
+    Sda: OutputPin<Error = E> + InputPin<Error = E>,
+    Delay: DelayUs<u32>,
+{
+    type Error = Error<E, Delay::Error>;
+
+    fn try_read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        transactional::read(self, address, buffer)
+    }
+}
+
+impl<Scl, Sda, Delay, E> Write for I2c<Scl, Sda, Delay>
+where
+    Scl: OutputPin<Error = E> + InputPin<Error = E>,
+    Sda: OutputPin<Error = E> + InputPin<Error = E>,
+    Delay: DelayUs<u32>,
+{
+    type Error = Error<E, Delay::Error>;
+
+    fn try_write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        transactional::write(self, address, bytes)
+    }
+}
+
+impl<Scl, Sda, Delay, E> WriteRead for I2c<Scl, Sda, Delay>
+where
+    Scl: OutputPin<Error = E> + InputPin<Error = E>,
+    Sda: OutputPin<Error = E> + InputPin<Error = E>,
+    Delay: DelayUs<u32>,
+{
+    type Error = Error<E, Delay::Error>;
+
+    fn try_write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        transactional::write_read(self, address, bytes, buffer)
+    }
+}
+
+impl<Scl, Sda, Delay, E> GeneralCall for I2c<Scl, Sda, Delay>
+where
+    Scl: OutputPin<Error = E> + InputPin<Error = E>,
+    Sda: OutputPin<Error = E> + InputPin<Error = E>,
+    Delay: DelayUs<u32>,
+{
+    type Error = Error<E, Delay::Error>;
+
+    fn try_general_call(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        general_call::write(self, bytes)
+    }
+}
+
+impl<Scl, Sda, Delay, E> Configure for I2c<Scl, Sda, Delay>
+where
+    Scl: OutputPin<Error = E> + InputPin<Error = E>,
+    Sda: OutputPin<Error = E> + InputPin<Error = E>,
+    Delay: DelayUs<u32>,
+{
+    type Error = Error<E, Delay::Error>;
+
+    /// Sets the SCL frequency to at most `hz`
+    ///
+    /// Returns [`Error::InvalidFrequency`] if `hz` is zero.
+    fn try_set_frequency(&mut self, hz: u32) -> Result<(), Self::Error> {
+        if hz == 0 {
+            return Err(Error::InvalidFrequency);
+        }
+
+        let half_period_us = 500_000 / hz + u32::from(500_000 % hz != 0);
+        self.half_period_us = half_period_us.max(1);
+
+        Ok(())
+    }
+}
+
+/// Rejects addresses that don't fit in the 7 bits of the address byte
+fn check_address<PinError, DelayError>(address: u8) -> Result<(), Error<PinError, DelayError>> {
+    if address > 0x7F {
+        Err(Error::InvalidAddress(address))
+    } else {
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+//! Software implementations of buses over GPIO pins
+//!
+//! The implementations in this module drive the bus signals with [`digital`](crate::digital)
+//! pins and use a [`DelayUs`](crate::blocking::delay::DelayUs) implementation for timing, so
+//! they work on any platform with GPIO, albeit at low speed and with CPU usage for the whole
+//! duration of each transfer.
+//!
+//! This module is only available when the `bitbang` feature is enabled.
+
+pub mod i2c;
+pub mod spi;
+
+pub use self::i2c::I2c;
+pub use self::spi::Spi;
@@ -0,0 +1,244 @@
+//! Bit-banged SPI master
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::bitbang;
+//! use embedded_hal::blocking::spi::Transfer;
+//! use embedded_hal::spi::MODE_0;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::delay::DelayUs;
+//! # use embedded_hal::digital::{InputPin, OutputPin};
+//! # struct Pin;
+//! # impl OutputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl InputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_is_high(&self) -> Result<bool, Self::Error> { Ok(true) }
+//! #     fn try_is_low(&self) -> Result<bool, Self::Error> { Ok(false) }
+//! # }
+//! # struct Delay;
+//! # impl DelayUs<u32> for Delay {
+//! #     type Error = Infallible;
+//! #     fn try_delay_us(&mut self, _: u32) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (sck, mosi, miso, delay) = (Pin, Pin, Pin, Delay);
+//!
+//! // 5 us half period, i.e. a 100 kHz clock
+//! let mut spi = bitbang::Spi::new(MODE_0, sck, mosi, miso, delay, 5).unwrap();
+//!
+//! let mut buf = [0x9F, 0x00];
+//! spi.try_transfer(&mut buf).unwrap();
+//! ```
+
+use crate::blocking::delay::DelayUs;
+use crate::blocking::spi::{flush, transactional, transfer, write, write_iter};
+use crate::digital::{InputPin, OutputPin, PinState};
+use crate::spi::{FullDuplex, Mode, Phase, Polarity};
+
+wrapper_error! {
+    /// Bit-banged SPI error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Error<SckError, MosiError, MisoError, DelayError> {
+        /// Underlying clock pin error
+        Sck(SckError) => Other, "clock pin error",
+        /// Underlying data out pin error
+        Mosi(MosiError) => Other, "data out pin error",
+        /// Underlying data in pin error
+        Miso(MisoError) => Other, "data in pin error",
+        /// Underlying delay error
+        Delay(DelayError) => Other, "delay error",
+    }
+
+    impl crate::spi::Error => crate::spi::ErrorKind
+}
+
+/// Error of a bus with the given pins and delay
+type BusError<Sck, Mosi, Miso, Delay> = Error<
+    <Sck as OutputPin>::Error,
+    <Mosi as OutputPin>::Error,
+    <Miso as InputPin>::Error,
+    <Delay as DelayUs<u32>>::Error,
+>;
+
+/// Bit-banged SPI master using a clock, a data out and a data in pin
+///
+/// Words are sent most significant bit first in any of the four SPI modes. The chip select line
+/// is not managed, wrap the bus in [`SpiWithCs`](crate::blocking::spi::spi_with_cs::SpiWithCs)
+/// to add one.
+///
+/// `spi::FullDuplex<u8>` is implemented, with each `try_send` call clocking a whole word, and
+/// the blocking SPI traits are provided through their `Default` implementations.
+pub struct Spi<Sck, Mosi, Miso, Delay> {
+    mode: Mode,
+    sck: Sck,
+    mosi: Mosi,
+    miso: Miso,
+    delay: Delay,
+    half_period_us: u32,
+    received: u8,
+}
+
+impl<Sck, Mosi, Miso, Delay> Spi<Sck, Mosi, Miso, Delay>
+where
+    Sck: OutputPin,
+    Mosi: OutputPin,
+    Miso: InputPin,
+    Delay: DelayUs<u32>,
+{
+    /// Creates a new bus, driving the clock pin to its idle level
+    ///
+    /// `half_period_us` is the time in microseconds between consecutive clock edges.
+    pub fn new(
+        mode: Mode,
+        mut sck: Sck,
+        mosi: Mosi,
+        miso: Miso,
+        delay: Delay,
+        half_period_us: u32,
+    ) -> Result<Self, BusError<Sck, Mosi, Miso, Delay>> {
+        sck.try_set_state(idle_state(mode)).map_err(Error::Sck)?;
+
+        Ok(Spi {
+            mode,
+            sck,
+            mosi,
+            miso,
+            delay,
+            half_period_us,
+            received: 0,
+        })
+    }
+
+    /// Destroys the bus, returning the pins and delay
+    pub fn destroy(self) -> (Sck, Mosi, Miso, Delay) {
+        (self.sck, self.mosi, self.miso, self.delay)
+    }
+
+    fn wait(&mut self) -> Result<(), BusError<Sck, Mosi, Miso, Delay>> {
+        self.delay
+            .try_delay_us(self.half_period_us)
+            .map_err(Error::Delay)
+    }
+
+    fn clock(&mut self, state: PinState) -> Result<(), BusError<Sck, Mosi, Miso, Delay>> {
+        self.sck.try_set_state(state).map_err(Error::Sck)
+    }
+
+    fn write_bit(&mut self, word: u8, bit: u32) -> Result<(), BusError<Sck, Mosi, Miso, Delay>> {
+        self.mosi
+            .try_set_state(PinState::from(word & (0x80 >> bit) != 0))
+            .map_err(Error::Mosi)
+    }
+
+    fn read_bit(
+        &mut self,
+        word: &mut u8,
+        bit: u32,
+    ) -> Result<(), BusError<Sck, Mosi, Miso, Delay>> {
+        if self.miso.try_is_high().map_err(Error::Miso)? {
+            *word |= 0x80 >> bit;
+        }
+
+        Ok(())
+    }
+}
+
+fn idle_state(mode: Mode) -> PinState {
+    match mode.polarity {
+        Polarity::IdleLow => PinState::Low,
+        Polarity::IdleHigh => PinState::High,
+    }
+}
+
+impl<Sck, Mosi, Miso, Delay> FullDuplex<u8> for Spi<Sck, Mosi, Miso, Delay>
+where
+    Sck: OutputPin,
+    Mosi: OutputPin,
+    Miso: InputPin,
+    Delay: DelayUs<u32>,
+{
+    type Error = Error<Sck::Error, Mosi::Error, Miso::Error, Delay::Error>;
+
+    fn try_read(&mut self) -> nb::Result<u8, Self::Error> {
+        Ok(self.received)
+    }
+
+    fn try_send(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+        let idle = idle_state(self.mode);
+        let mut received = 0;
+
+        for bit in 0..8 {
+            match self.mode.phase {
+                Phase::CaptureOnFirstTransition => {
+                    self.write_bit(word, bit)?;
+                    self.wait()?;
+                    self.clock(!idle)?;
+                    self.read_bit(&mut received, bit)?;
+                    self.wait()?;
+                    self.clock(idle)?;
+                }
+                Phase::CaptureOnSecondTransition => {
+                    self.clock(!idle)?;
+                    self.write_bit(word, bit)?;
+                    self.wait()?;
+                    self.clock(idle)?;
+                    self.read_bit(&mut received, bit)?;
+                    self.wait()?;
+                }
+            }
+        }
+
+        self.received = received;
+
+        Ok(())
+    }
+}
+
+impl<Sck, Mosi, Miso, Delay> flush::Default<u8> for Spi<Sck, Mosi, Miso, Delay>
+where
+    Sck: OutputPin,
+    Mosi: OutputPin,
+    Miso: InputPin,
+    Delay: DelayUs<u32>,
+{
+}
+
+impl<Sck, Mosi, Miso, Delay> transfer::Default<u8> for Spi<Sck, Mosi, Miso, Delay>
+where
+    Sck: OutputPin,
+    Mosi: OutputPin,
+    Miso: InputPin,
+    Delay: DelayUs<u32>,
+{
+}
+
+impl<Sck, Mosi, Miso, Delay> write::Default<u8> for Spi<Sck, Mosi, Miso, Delay>
+where
+    Sck: OutputPin,
+    Mosi: OutputPin,
+    Miso: InputPin,
+    Delay: DelayUs<u32>,
+{
+}
+
+impl<Sck, Mosi, Miso, Delay> write_iter::Default<u8> for Spi<Sck, Mosi, Miso, Delay>
+where
+    Sck: OutputPin,
+    Mosi: OutputPin,
+    Miso: InputPin,
+    Delay: DelayUs<u32>,
+{
+}
+
+impl<Sck, Mosi, Miso, Delay> transactional::Default<u8> for Spi<Sck, Mosi, Miso, Delay>
+where
+    Sck: OutputPin,
+    Mosi: OutputPin,
+    Miso: InputPin,
+    Delay: DelayUs<u32>,
+{
+}
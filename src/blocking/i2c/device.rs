@@ -0,0 +1,144 @@
+//! I2C device bound to an address
+//!
+//! Most drivers talk to a single device and would otherwise pass the same address to every
+//! call. [`I2cDevice`] stores the address alongside the bus and provides the usual register
+//! accesses on top of it.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::device::I2cDevice;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::i2c::WriteRead;
+//! # struct I2c;
+//! # impl WriteRead for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write_read(&mut self, _: u8, _: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+//! #         buffer.copy_from_slice(&[0x19, 0x80]);
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let i2c = I2c;
+//!
+//! const TEMPERATURE: u8 = 0x00;
+//!
+//! let mut sensor = I2cDevice::new(i2c, 0x48);
+//!
+//! let mut temperature = [0; 2];
+//! sensor.try_read_reg(TEMPERATURE, &mut temperature).unwrap();
+//! assert_eq!(temperature, [0x19, 0x80]);
+//! ```
+//!
+//! A bus shared with other devices can be borrowed instead:
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::device::I2cDevice;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::i2c::{Operation, Transactional};
+//! # struct I2c;
+//! # impl Transactional for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_exec<'a>(&mut self, _: u8, _: &mut [Operation<'a>]) -> Result<(), Self::Error> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let mut bus = I2c;
+//!
+//! const CONFIGURATION: u8 = 0x01;
+//!
+//! I2cDevice::new(&mut bus, 0x48)
+//!     .try_write_reg(CONFIGURATION, &[0x60, 0xa0])
+//!     .unwrap();
+//! I2cDevice::new(&mut bus, 0x49)
+//!     .try_write_reg(CONFIGURATION, &[0x60, 0xa0])
+//!     .unwrap();
+//! ```
+
+use super::{Operation, Read, SevenBitAddress, Transactional, Write, WriteIter, WriteRead};
+
+/// I2C device with a 7-bit address
+pub struct I2cDevice<I2C> {
+    i2c: I2C,
+    address: SevenBitAddress,
+}
+
+impl<I2C> I2cDevice<I2C> {
+    /// Creates a new device with `address` on the provided bus
+    ///
+    /// The bus can be a [`BusProxy`](super::shared::BusProxy) or a `&mut` reference when it is
+    /// shared with other devices.
+    pub fn new(i2c: I2C, address: SevenBitAddress) -> Self {
+        Self { i2c, address }
+    }
+
+    /// Returns the address of the device
+    pub fn address(&self) -> SevenBitAddress {
+        self.address
+    }
+
+    /// Returns a mutable reference to the bus
+    pub fn bus(&mut self) -> &mut I2C {
+        &mut self.i2c
+    }
+
+    /// Destroys the device, returning the bus
+    pub fn destroy(self) -> I2C {
+        self.i2c
+    }
+
+    /// Writes `bytes` to the device
+    pub fn try_write(&mut self, bytes: &[u8]) -> Result<(), I2C::Error>
+    where
+        I2C: Write,
+    {
+        self.i2c.try_write(self.address, bytes)
+    }
+
+    /// Writes the bytes yielded by `bytes` to the device
+    pub fn try_write_iter<B>(&mut self, bytes: B) -> Result<(), I2C::Error>
+    where
+        I2C: WriteIter,
+        B: IntoIterator<Item = u8>,
+    {
+        self.i2c.try_write_iter(self.address, bytes)
+    }
+
+    /// Reads enough bytes from the device to fill `buffer`
+    pub fn try_read(&mut self, buffer: &mut [u8]) -> Result<(), I2C::Error>
+    where
+        I2C: Read,
+    {
+        self.i2c.try_read(self.address, buffer)
+    }
+
+    /// Writes `bytes` to the device, then reads enough bytes to fill `buffer` in the same
+    /// transaction
+    pub fn try_write_read(&mut self, bytes: &[u8], buffer: &mut [u8]) -> Result<(), I2C::Error>
+    where
+        I2C: WriteRead,
+    {
+        self.i2c.try_write_read(self.address, bytes, buffer)
+    }
+
+    /// Writes `data` to the registers of the device starting at `register`
+    ///
+    /// The register address and data are sent in a single write, without copying them into
+    /// one buffer.
+    pub fn try_write_reg(&mut self, register: u8, data: &[u8]) -> Result<(), I2C::Error>
+    where
+        I2C: Transactional,
+    {
+        self.i2c.try_exec(
+            self.address,
+            &mut [Operation::Write(&[register]), Operation::Write(data)],
+        )
+    }
+
+    /// Reads the registers of the device starting at `register` into `buffer`
+    pub fn try_read_reg(&mut self, register: u8, buffer: &mut [u8]) -> Result<(), I2C::Error>
+    where
+        I2C: WriteRead,
+    {
+        self.i2c.try_write_read(self.address, &[register], buffer)
+    }
+}
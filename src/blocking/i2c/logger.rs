@@ -0,0 +1,201 @@
+//! Tracing of I2C traffic
+//!
+//! [`I2cLogger`] wraps a blocking I2C implementation and reports every byte sent to and received
+//! from slaves to a user provided callback, which makes it possible to debug a driver without a
+//! bus analyzer.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::logger::{Direction, I2cLogger};
+//! use embedded_hal::blocking::i2c::WriteRead;
+//! # use core::convert::Infallible;
+//! # struct I2c;
+//! # impl WriteRead for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write_read(&mut self, _: u8, _: &[u8], _: &mut [u8]) -> Result<(), Self::Error> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let i2c = I2c;
+//!
+//! let mut i2c = I2cLogger::new(i2c, |address: u8, direction: Direction, bytes: &[u8]| {
+//!     // e.g. forward to a debug console
+//!     println!("{:#04x} {:?} {:02x?}", address, direction, bytes);
+//! });
+//!
+//! let mut temperature = [0; 2];
+//! i2c.try_write_read(0x48, &[0x00], &mut temperature).unwrap();
+//! ```
+
+use super::{
+    AddressMode, Operation, Read, Transactional, Write, WriteIter, WriteIterRead, WriteRead,
+};
+
+/// Direction of the reported bytes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Bytes written by the master
+    Write,
+    /// Bytes read from the slave
+    Read,
+}
+
+/// Wraps a blocking I2C implementation, passing all traffic to a callback
+///
+/// For each call the callback is first invoked with [`Direction::Write`] and the bytes about to
+/// be written, then the call is forwarded to the inner bus. Bytes read from the slave are
+/// reported afterwards with [`Direction::Read`]. Iterator writes are reported one byte at a
+/// time. Failed calls only report the bytes written before forwarding the call.
+pub struct I2cLogger<I2C, F> {
+    i2c: I2C,
+    f: F,
+}
+
+impl<I2C, F> I2cLogger<I2C, F> {
+    /// Creates a new logger wrapping `i2c` and reporting to `f`
+    pub fn new(i2c: I2C, f: F) -> Self {
+        Self { i2c, f }
+    }
+
+    /// Destroys the logger, returning the I2C bus and callback
+    pub fn destroy(self) -> (I2C, F) {
+        (self.i2c, self.f)
+    }
+}
+
+impl<A, I2C, F> Read<A> for I2cLogger<I2C, F>
+where
+    A: AddressMode + Copy,
+    I2C: Read<A>,
+    F: FnMut(A, Direction, &[u8]),
+{
+    type Error = I2C::Error;
+
+    fn try_read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.i2c.try_read(address, buffer)?;
+        (self.f)(address, Direction::Read, buffer);
+
+        Ok(())
+    }
+}
+
+impl<A, I2C, F> Write<A> for I2cLogger<I2C, F>
+where
+    A: AddressMode + Copy,
+    I2C: Write<A>,
+    F: FnMut(A, Direction, &[u8]),
+{
+    type Error = I2C::Error;
+
+    fn try_write(&mut self, address: A, bytes: &[u8]) -> Result<(), Self::Error> {
+        (self.f)(address, Direction::Write, bytes);
+        self.i2c.try_write(address, bytes)
+    }
+}
+
+impl<A, I2C, F> WriteIter<A> for I2cLogger<I2C, F>
+where
+    A: AddressMode + Copy,
+    I2C: WriteIter<A>,
+    F: FnMut(A, Direction, &[u8]),
+{
+    type Error = I2C::Error;
+
+    fn try_write_iter<B>(&mut self, address: A, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        let f = &mut self.f;
+        self.i2c.try_write_iter(
+            address,
+            bytes
+                .into_iter()
+                .inspect(|byte| f(address, Direction::Write, core::slice::from_ref(byte))),
+        )
+    }
+}
+
+impl<A, I2C, F> WriteRead<A> for I2cLogger<I2C, F>
+where
+    A: AddressMode + Copy,
+    I2C: WriteRead<A>,
+    F: FnMut(A, Direction, &[u8]),
+{
+    type Error = I2C::Error;
+
+    fn try_write_read(
+        &mut self,
+        address: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        (self.f)(address, Direction::Write, bytes);
+        self.i2c.try_write_read(address, bytes, buffer)?;
+        (self.f)(address, Direction::Read, buffer);
+
+        Ok(())
+    }
+}
+
+impl<A, I2C, F> WriteIterRead<A> for I2cLogger<I2C, F>
+where
+    A: AddressMode + Copy,
+    I2C: WriteIterRead<A>,
+    F: FnMut(A, Direction, &[u8]),
+{
+    type Error = I2C::Error;
+
+    fn try_write_iter_read<B>(
+        &mut self,
+        address: A,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        let f = &mut self.f;
+        self.i2c.try_write_iter_read(
+            address,
+            bytes
+                .into_iter()
+                .inspect(|byte| f(address, Direction::Write, core::slice::from_ref(byte))),
+            buffer,
+        )?;
+        (self.f)(address, Direction::Read, buffer);
+
+        Ok(())
+    }
+}
+
+impl<A, I2C, F> Transactional<A> for I2cLogger<I2C, F>
+where
+    A: AddressMode + Copy,
+    I2C: Transactional<A>,
+    F: FnMut(A, Direction, &[u8]),
+{
+    type Error = I2C::Error;
+
+    fn try_exec<'a>(
+        &mut self,
+        address: A,
+        operations: &mut [Operation<'a>],
+    ) -> Result<(), Self::Error> {
+        for op in operations.iter() {
+            if let Operation::Write(bytes) = op {
+                (self.f)(address, Direction::Write, bytes);
+            }
+        }
+
+        self.i2c.try_exec(address, operations)?;
+
+        for op in operations.iter() {
+            if let Operation::Read(buffer) = op {
+                (self.f)(address, Direction::Read, buffer);
+            }
+        }
+
+        Ok(())
+    }
+}
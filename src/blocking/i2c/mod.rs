@@ -95,6 +95,16 @@
 //! }
 //! ```
 
+pub mod device;
+pub mod logger;
+pub mod multi_master;
+pub mod mux;
+pub mod recovery;
+pub mod retry;
+pub mod scan;
+pub mod shared;
+
+pub use crate::i2c::{Error, ErrorKind, NoAcknowledgeSource};
 use crate::private;
 
 /// Address mode (7-bit / 10-bit)
@@ -163,6 +173,20 @@ pub trait Write<A: AddressMode = SevenBitAddress> {
 }
 
 /// Blocking write (iterator version)
+///
+/// This allows streaming data that is generated on the fly, without staging it in a buffer
+/// first:
+///
+/// ```
+/// use embedded_hal::blocking::i2c::WriteIter;
+///
+/// /// Fills the 128x64 display with a checkerboard pattern
+/// fn checkerboard<I2C: WriteIter>(i2c: &mut I2C) -> Result<(), I2C::Error> {
+///     // control byte announcing display data, then 1024 bytes of pixels
+///     let pixels = (0..1024).map(|i| if i % 16 < 8 { 0xF0 } else { 0x0F });
+///     i2c.try_write_iter(0x3C, core::iter::once(0x40).chain(pixels))
+/// }
+/// ```
 pub trait WriteIter<A: AddressMode = SevenBitAddress> {
     /// Error type
     type Error;
@@ -246,8 +270,23 @@ pub enum Operation<'a> {
 
 /// Transactional I2C interface.
 ///
-/// This allows combining operations within an I2C transaction.
-pub trait Transactional {
+/// This allows combining operations within an I2C transaction, e.g. for devices that expect
+/// a register address and data from separate buffers in a single write, or sequences that
+/// can't be expressed with `Write`, `Read` and `WriteRead`.
+///
+/// ```
+/// use embedded_hal::blocking::i2c::{Operation, Transactional};
+///
+/// /// Writes `data` to the EEPROM page at `address`, without copying it into a single buffer
+/// fn write_page<I2C>(i2c: &mut I2C, address: u16, data: &[u8]) -> Result<(), I2C::Error>
+/// where
+///     I2C: Transactional,
+/// {
+///     let address = address.to_be_bytes();
+///     i2c.try_exec(0x50, &mut [Operation::Write(&address), Operation::Write(data)])
+/// }
+/// ```
+pub trait Transactional<A: AddressMode = SevenBitAddress> {
     /// Error type
     type Error;
 
@@ -266,7 +305,7 @@ pub trait Transactional {
     /// - `SP` = stop condition
     fn try_exec<'a>(
         &mut self,
-        address: u8,
+        address: A,
         operations: &mut [Operation<'a>],
     ) -> Result<(), Self::Error>;
 }
@@ -274,7 +313,7 @@ pub trait Transactional {
 /// Transactional I2C interface (iterator version).
 ///
 /// This allows combining operation within an I2C transaction.
-pub trait TransactionalIter {
+pub trait TransactionalIter<A: AddressMode = SevenBitAddress> {
     /// Error type
     type Error;
 
@@ -291,58 +330,251 @@ pub trait TransactionalIter {
     /// - `SAD+R/W` = slave address followed by bit 1 to indicate reading or 0 to indicate writing
     /// - `SR` = repeated start condition
     /// - `SP` = stop condition
-    fn try_exec_iter<'a, O>(&mut self, address: u8, operations: O) -> Result<(), Self::Error>
+    fn try_exec_iter<'a, O>(&mut self, address: A, operations: O) -> Result<(), Self::Error>
     where
         O: IntoIterator<Item = Operation<'a>>;
 }
 
-/// Default implementation of `blocking::i2c::Write`, `blocking::i2c::Read` and
-/// `blocking::i2c::WriteRead` traits for `blocking::i2c::Transactional` implementers.
-pub mod transactional {
-    use super::{Operation, Read, Transactional, Write, WriteRead};
+/// General call address, addressing all the slaves on the bus at once
+pub const GENERAL_CALL: SevenBitAddress = 0x00;
+
+/// Blocking general call write
+///
+/// Writes to the [`GENERAL_CALL`] address are received by every slave supporting it, e.g. to
+/// update all the outputs of several DACs at once. As the address is reserved, HALs may reject
+/// it in `Write`; implementers of this trait guarantee that it is actually sent on the bus.
+///
+/// ```
+/// use embedded_hal::blocking::i2c::GeneralCall;
+///
+/// fn reset_all<I2C: GeneralCall>(i2c: &mut I2C) -> Result<(), I2C::Error> {
+///     i2c.try_software_reset()
+/// }
+/// ```
+pub trait GeneralCall {
+    /// Error type
+    type Error;
+
+    /// Writes `bytes` to the general call address
+    ///
+    /// The first byte is the general call command. Its least significant bit set means that the
+    /// remaining bytes start with the address of the master, as sent by hardware masters.
+    fn try_general_call(&mut self, bytes: &[u8]) -> Result<(), Self::Error>;
+
+    /// Sends the general call software reset command, `0x06`
+    ///
+    /// Slaves supporting it reset and take in the programmable part of their address.
+    fn try_software_reset(&mut self) -> Result<(), Self::Error> {
+        self.try_general_call(&[0x06])
+    }
+}
+
+/// Implementation of `blocking::i2c::GeneralCall` in terms of `blocking::i2c::Write`
+///
+/// ```
+/// use embedded_hal::blocking::i2c::{general_call, GeneralCall, Write};
+///
+/// struct I2c;
+///
+/// impl Write for I2c {
+///     type Error = ();
+///
+///     fn try_write(&mut self, address: u8, bytes: &[u8]) -> Result<(), ()> {
+///         // ...
+/// #       Ok(())
+///     }
+/// }
+///
+/// impl GeneralCall for I2c {
+///     type Error = ();
+///
+///     fn try_general_call(&mut self, bytes: &[u8]) -> Result<(), ()> {
+///         general_call::write(self, bytes)
+///     }
+/// }
+/// ```
+pub mod general_call {
+    use super::{Write, GENERAL_CALL};
+
+    /// Writes `bytes` to the general call address
+    ///
+    /// Only suitable for implementations of `Write` that don't reject the general call address.
+    pub fn write<T>(i2c: &mut T, bytes: &[u8]) -> Result<(), T::Error>
+    where
+        T: Write + ?Sized,
+    {
+        i2c.try_write(GENERAL_CALL, bytes)
+    }
+}
+
+/// Blocking bus recovery
+///
+/// A slave that was interrupted in the middle of a transfer, e.g. by a reset of the master, may
+/// keep driving SDA low while it waits for the clock pulses of the rest of the byte, blocking
+/// the bus. Recovery consists of clocking SCL, up to 9 times, until the slave releases SDA and
+/// then issuing a stop condition.
+///
+/// See [`recovery::GpioBusClear`] for an implementation over GPIO pins.
+pub trait BusClear {
+    /// Error type
+    type Error;
+
+    /// Releases the bus from a slave holding SDA low
+    fn try_clear_bus(&mut self) -> Result<(), Self::Error>;
+}
+
+impl<A: AddressMode, T: Read<A> + ?Sized> Read<A> for &mut T {
+    type Error = T::Error;
+
+    fn try_read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        T::try_read(self, address, buffer)
+    }
+}
+
+impl<A: AddressMode, T: Write<A> + ?Sized> Write<A> for &mut T {
+    type Error = T::Error;
+
+    fn try_write(&mut self, address: A, bytes: &[u8]) -> Result<(), Self::Error> {
+        T::try_write(self, address, bytes)
+    }
+}
+
+impl<A: AddressMode, T: WriteRead<A> + ?Sized> WriteRead<A> for &mut T {
+    type Error = T::Error;
+
+    fn try_write_read(
+        &mut self,
+        address: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        T::try_write_read(self, address, bytes, buffer)
+    }
+}
 
-    /// Default implementation of `blocking::i2c::Write`, `blocking::i2c::Read` and
-    /// `blocking::i2c::WriteRead` traits for `blocking::i2c::Transactional` implementers.
-    pub trait Default<E> {}
+impl<A: AddressMode, T: WriteIter<A> + ?Sized> WriteIter<A> for &mut T {
+    type Error = T::Error;
 
-    impl<E, S> Write for S
+    fn try_write_iter<B>(&mut self, address: A, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        T::try_write_iter(self, address, bytes)
+    }
+}
+
+impl<A: AddressMode, T: WriteIterRead<A> + ?Sized> WriteIterRead<A> for &mut T {
+    type Error = T::Error;
+
+    fn try_write_iter_read<B>(
+        &mut self,
+        address: A,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
     where
-        S: self::Default<E> + Transactional<Error = E>,
+        B: IntoIterator<Item = u8>,
     {
-        type Error = E;
+        T::try_write_iter_read(self, address, bytes, buffer)
+    }
+}
+
+impl<T: GeneralCall + ?Sized> GeneralCall for &mut T {
+    type Error = T::Error;
+
+    fn try_general_call(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        T::try_general_call(self, bytes)
+    }
+
+    fn try_software_reset(&mut self) -> Result<(), Self::Error> {
+        T::try_software_reset(self)
+    }
+}
 
-        fn try_write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
-            self.try_exec(address, &mut [Operation::Write(bytes)])
-        }
+impl<A: AddressMode, T: Transactional<A> + ?Sized> Transactional<A> for &mut T {
+    type Error = T::Error;
+
+    fn try_exec<'a>(
+        &mut self,
+        address: A,
+        operations: &mut [Operation<'a>],
+    ) -> Result<(), Self::Error> {
+        T::try_exec(self, address, operations)
     }
+}
+
+impl<A: AddressMode, T: TransactionalIter<A> + ?Sized> TransactionalIter<A> for &mut T {
+    type Error = T::Error;
+
+    fn try_exec_iter<'a, O>(&mut self, address: A, operations: O) -> Result<(), Self::Error>
+    where
+        O: IntoIterator<Item = Operation<'a>>,
+    {
+        T::try_exec_iter(self, address, operations)
+    }
+}
+
+/// Implementations of `blocking::i2c::Write`, `blocking::i2c::Read` and
+/// `blocking::i2c::WriteRead` in terms of `blocking::i2c::Transactional`
+///
+/// ```
+/// use embedded_hal::blocking::i2c::{transactional, Operation, Read, Transactional};
+///
+/// struct I2c;
+///
+/// impl Transactional for I2c {
+///     type Error = ();
+///
+///     fn try_exec(&mut self, address: u8, operations: &mut [Operation]) -> Result<(), ()> {
+///         // ...
+/// #       Ok(())
+///     }
+/// }
+///
+/// impl Read for I2c {
+///     type Error = ();
+///
+///     fn try_read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), ()> {
+///         transactional::read(self, address, buffer)
+///     }
+/// }
+/// ```
+pub mod transactional {
+    use super::{AddressMode, Operation, Transactional};
 
-    impl<E, S> Read for S
+    /// Writes `bytes` to the slave with `address` as a single `Write` operation
+    pub fn write<A, T>(i2c: &mut T, address: A, bytes: &[u8]) -> Result<(), T::Error>
     where
-        S: self::Default<E> + Transactional<Error = E>,
+        A: AddressMode,
+        T: Transactional<A> + ?Sized,
     {
-        type Error = E;
+        i2c.try_exec(address, &mut [Operation::Write(bytes)])
+    }
 
-        fn try_read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
-            self.try_exec(address, &mut [Operation::Read(buffer)])
-        }
+    /// Reads from the slave with `address` into `buffer` as a single `Read` operation
+    pub fn read<A, T>(i2c: &mut T, address: A, buffer: &mut [u8]) -> Result<(), T::Error>
+    where
+        A: AddressMode,
+        T: Transactional<A> + ?Sized,
+    {
+        i2c.try_exec(address, &mut [Operation::Read(buffer)])
     }
 
-    impl<E, S> WriteRead for S
+    /// Writes `bytes` to the slave with `address` then reads into `buffer`, as a `Write` and a
+    /// `Read` operation
+    pub fn write_read<A, T>(
+        i2c: &mut T,
+        address: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), T::Error>
     where
-        S: self::Default<E> + Transactional<Error = E>,
+        A: AddressMode,
+        T: Transactional<A> + ?Sized,
     {
-        type Error = E;
-
-        fn try_write_read(
-            &mut self,
-            address: u8,
-            bytes: &[u8],
-            buffer: &mut [u8],
-        ) -> Result<(), Self::Error> {
-            self.try_exec(
-                address,
-                &mut [Operation::Write(bytes), Operation::Read(buffer)],
-            )
-        }
+        i2c.try_exec(
+            address,
+            &mut [Operation::Write(bytes), Operation::Read(buffer)],
+        )
     }
 }
@@ -0,0 +1,179 @@
+//! Sharing an I2C bus with other masters
+//!
+//! When several masters start a transfer at the same time, all but one of them lose the
+//! arbitration and have to try again once the bus is idle. [`MultiMaster`] repeats operations
+//! that failed with [`ErrorKind::ArbitrationLoss`], after a random delay so that the masters
+//! don't keep colliding. Other errors are reported immediately.
+//!
+//! Iterator based operations can't be repeated, so `WriteIter`, `WriteIterRead` and
+//! `TransactionalIter` are not implemented.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::multi_master::MultiMaster;
+//! use embedded_hal::blocking::i2c::Write;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::delay::DelayUs;
+//! # use embedded_hal::blocking::rng;
+//! # struct I2c;
+//! # impl Write for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write(&mut self, _: u8, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # struct Delay;
+//! # impl DelayUs<u32> for Delay {
+//! #     type Error = Infallible;
+//! #     fn try_delay_us(&mut self, _: u32) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # struct Rng;
+//! # impl rng::Read for Rng {
+//! #     type Error = Infallible;
+//! #     fn try_read(&mut self, _: &mut [u8]) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (i2c, delay, rng) = (I2c, Delay, Rng);
+//!
+//! // up to 5 retries, each after a random delay of at most 1 ms
+//! let mut i2c = MultiMaster::new(i2c, delay, rng, 5, 1_000);
+//! i2c.try_write(0x3C, &[0x00, 0xAF]).unwrap();
+//! ```
+
+use super::retry::{retry, I2cRetryError};
+use super::{AddressMode, Error, ErrorKind, Operation, Read, Transactional, Write, WriteRead};
+use crate::blocking::delay::DelayUs;
+use crate::blocking::rng;
+
+/// Wraps a blocking I2C implementation, retrying operations after losing the arbitration
+pub struct MultiMaster<I2C, Delay, Rng> {
+    i2c: I2C,
+    delay: Delay,
+    rng: Rng,
+    retries: usize,
+    max_backoff_us: u32,
+}
+
+impl<I2C, Delay, Rng> MultiMaster<I2C, Delay, Rng>
+where
+    Delay: DelayUs<u32>,
+    Rng: rng::Read,
+{
+    /// Creates a new wrapper retrying operations up to `retries` times after losing the
+    /// arbitration, waiting a random delay of at most `max_backoff_us` microseconds before each
+    /// retry
+    pub fn new(i2c: I2C, delay: Delay, rng: Rng, retries: usize, max_backoff_us: u32) -> Self {
+        Self {
+            i2c,
+            delay,
+            rng,
+            retries,
+            max_backoff_us,
+        }
+    }
+
+    /// Destroys the wrapper, returning the I2C bus, delay and random number generator
+    pub fn destroy(self) -> (I2C, Delay, Rng) {
+        (self.i2c, self.delay, self.rng)
+    }
+
+    /// Runs `f` on the inner bus, running it again after a random delay as long as it fails
+    /// with [`ErrorKind::ArbitrationLoss`] and retries remain
+    pub fn retry<R, E, F>(&mut self, f: F) -> Result<R, I2cRetryError<E, Delay::Error, Rng::Error>>
+    where
+        E: Error,
+        F: FnMut(&mut I2C) -> Result<R, E>,
+    {
+        let Self {
+            i2c,
+            delay,
+            rng,
+            retries,
+            max_backoff_us,
+        } = self;
+
+        retry(
+            i2c,
+            *retries,
+            |kind| kind == ErrorKind::ArbitrationLoss,
+            || {
+                let mut random = [0; 4];
+                rng.try_read(&mut random).map_err(I2cRetryError::Rng)?;
+
+                let us = match max_backoff_us.checked_add(1) {
+                    Some(range) => u32::from_le_bytes(random) % range,
+                    None => u32::from_le_bytes(random),
+                };
+                delay.try_delay_us(us).map_err(I2cRetryError::Delay)
+            },
+            f,
+        )
+    }
+}
+
+impl<A, I2C, Delay, Rng> Read<A> for MultiMaster<I2C, Delay, Rng>
+where
+    A: AddressMode + Copy,
+    I2C: Read<A>,
+    I2C::Error: Error,
+    Delay: DelayUs<u32>,
+    Rng: rng::Read,
+{
+    type Error = I2cRetryError<I2C::Error, Delay::Error, Rng::Error>;
+
+    fn try_read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.retry(|i2c| i2c.try_read(address, buffer))
+    }
+}
+
+impl<A, I2C, Delay, Rng> Write<A> for MultiMaster<I2C, Delay, Rng>
+where
+    A: AddressMode + Copy,
+    I2C: Write<A>,
+    I2C::Error: Error,
+    Delay: DelayUs<u32>,
+    Rng: rng::Read,
+{
+    type Error = I2cRetryError<I2C::Error, Delay::Error, Rng::Error>;
+
+    fn try_write(&mut self, address: A, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.retry(|i2c| i2c.try_write(address, bytes))
+    }
+}
+
+impl<A, I2C, Delay, Rng> WriteRead<A> for MultiMaster<I2C, Delay, Rng>
+where
+    A: AddressMode + Copy,
+    I2C: WriteRead<A>,
+    I2C::Error: Error,
+    Delay: DelayUs<u32>,
+    Rng: rng::Read,
+{
+    type Error = I2cRetryError<I2C::Error, Delay::Error, Rng::Error>;
+
+    fn try_write_read(
+        &mut self,
+        address: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.retry(|i2c| i2c.try_write_read(address, bytes, buffer))
+    }
+}
+
+impl<A, I2C, Delay, Rng> Transactional<A> for MultiMaster<I2C, Delay, Rng>
+where
+    A: AddressMode + Copy,
+    I2C: Transactional<A>,
+    I2C::Error: Error,
+    Delay: DelayUs<u32>,
+    Rng: rng::Read,
+{
+    type Error = I2cRetryError<I2C::Error, Delay::Error, Rng::Error>;
+
+    fn try_exec<'a>(
+        &mut self,
+        address: A,
+        operations: &mut [Operation<'a>],
+    ) -> Result<(), Self::Error> {
+        self.retry(|i2c| i2c.try_exec(address, operations))
+    }
+}
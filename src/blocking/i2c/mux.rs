@@ -0,0 +1,275 @@
+//! Devices behind an I2C multiplexer
+//!
+//! Multiplexers such as the TCA9548A connect the bus of the master to one of several downstream
+//! buses, which allows using several devices with the same address. An [`I2cMuxChannel`] makes
+//! a downstream bus look like a regular I2C bus to drivers, by selecting the channel before
+//! each call and deselecting it afterwards.
+//!
+//! The multiplexer is controlled through a [`MuxControl`] implementation, either [`Tca9548`]
+//! or a closure. Several channels can share the same upstream bus through
+//! [`shared::SharedBus`](super::shared::SharedBus).
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::mux::{I2cMuxChannel, Tca9548};
+//! use embedded_hal::blocking::i2c::shared::SharedBus;
+//! use embedded_hal::blocking::i2c::WriteRead;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::i2c::Write;
+//! # struct I2c;
+//! # impl Write for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write(&mut self, _: u8, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl WriteRead for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write_read(&mut self, _: u8, _: &[u8], _: &mut [u8]) -> Result<(), Self::Error> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let i2c = I2c;
+//!
+//! let bus = SharedBus::new(i2c);
+//! let mux = Tca9548::new(0x70);
+//!
+//! // two sensors with the same address on different channels
+//! let mut left = I2cMuxChannel::new(bus.acquire(), mux, 0);
+//! let mut right = I2cMuxChannel::new(bus.acquire(), mux, 1);
+//!
+//! let mut temperature = [0; 2];
+//! left.try_write_read(0x48, &[0x00], &mut temperature).unwrap();
+//! right.try_write_read(0x48, &[0x00], &mut temperature).unwrap();
+//! ```
+
+use super::{
+    AddressMode, Operation, Read, Transactional, Write, WriteIter, WriteIterRead, WriteRead,
+};
+
+/// Control of an I2C multiplexer
+///
+/// This is implemented for closures taking the upstream bus and the channel to select, or
+/// `None` to deselect all channels.
+pub trait MuxControl<I2C> {
+    /// Error type
+    type Error;
+
+    /// Connects the downstream bus `channel`
+    fn try_select(&mut self, i2c: &mut I2C, channel: u8) -> Result<(), Self::Error>;
+
+    /// Disconnects all the downstream buses
+    fn try_deselect(&mut self, i2c: &mut I2C) -> Result<(), Self::Error>;
+}
+
+impl<I2C, F, E> MuxControl<I2C> for F
+where
+    F: FnMut(&mut I2C, Option<u8>) -> Result<(), E>,
+{
+    type Error = E;
+
+    fn try_select(&mut self, i2c: &mut I2C, channel: u8) -> Result<(), Self::Error> {
+        self(i2c, Some(channel))
+    }
+
+    fn try_deselect(&mut self, i2c: &mut I2C) -> Result<(), Self::Error> {
+        self(i2c, None)
+    }
+}
+
+/// TCA9548A / PCA9548A 8 channel multiplexer, controlled over the upstream bus
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tca9548 {
+    address: u8,
+}
+
+impl Tca9548 {
+    /// Creates a control handle for the multiplexer with `address`, from `0x70` to `0x77`
+    pub fn new(address: u8) -> Self {
+        Self { address }
+    }
+}
+
+impl<I2C: Write> MuxControl<I2C> for Tca9548 {
+    type Error = Tca9548Error<I2C::Error>;
+
+    /// Connects the downstream bus `channel`, from `0` to `7`
+    fn try_select(&mut self, i2c: &mut I2C, channel: u8) -> Result<(), Self::Error> {
+        let mask = 1u8
+            .checked_shl(u32::from(channel))
+            .ok_or(Tca9548Error::InvalidChannel(channel))?;
+
+        i2c.try_write(self.address, &[mask])
+            .map_err(Tca9548Error::I2c)
+    }
+
+    fn try_deselect(&mut self, i2c: &mut I2C) -> Result<(), Self::Error> {
+        i2c.try_write(self.address, &[0]).map_err(Tca9548Error::I2c)
+    }
+}
+
+wrapper_error! {
+    /// TCA9548A control error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Tca9548Error<E> {
+        /// Error writing the control register
+        I2c(E) => forward, "I2C error",
+        /// The channel is not one of the 8 channels of the multiplexer
+        InvalidChannel(u8) => Other, "invalid channel",
+    }
+
+    impl super::Error => super::ErrorKind where E: super::Error
+}
+
+wrapper_error! {
+    /// Multiplexed I2C error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum I2cMuxError<I2cError, MuxError> {
+        /// Error of the operation on the downstream bus
+        I2c(I2cError) => forward, "I2C error",
+        /// Error selecting or deselecting the channel
+        Mux(MuxError) => forward, "multiplexer error",
+    }
+
+    impl super::Error => super::ErrorKind where I2cError: super::Error, MuxError: super::Error
+}
+
+/// Downstream bus of an I2C multiplexer
+///
+/// Implements the blocking I2C traits of the upstream bus, except `TransactionalIter`.
+pub struct I2cMuxChannel<I2C, Mux> {
+    i2c: I2C,
+    mux: Mux,
+    channel: u8,
+}
+
+impl<I2C, Mux> I2cMuxChannel<I2C, Mux>
+where
+    Mux: MuxControl<I2C>,
+{
+    /// Creates a new downstream bus, selected with `mux` on the upstream bus `i2c`
+    pub fn new(i2c: I2C, mux: Mux, channel: u8) -> Self {
+        Self { i2c, mux, channel }
+    }
+
+    /// Destroys the channel, returning the upstream bus and the multiplexer control
+    pub fn destroy(self) -> (I2C, Mux) {
+        (self.i2c, self.mux)
+    }
+
+    /// Runs `f` with the channel selected
+    ///
+    /// The channel is deselected even if `f` fails, but the error of `f` is returned first.
+    fn with<E>(
+        &mut self,
+        f: impl FnOnce(&mut I2C) -> Result<(), E>,
+    ) -> Result<(), I2cMuxError<E, Mux::Error>> {
+        self.mux
+            .try_select(&mut self.i2c, self.channel)
+            .map_err(I2cMuxError::Mux)?;
+        let result = f(&mut self.i2c).map_err(I2cMuxError::I2c);
+        let deselect = self
+            .mux
+            .try_deselect(&mut self.i2c)
+            .map_err(I2cMuxError::Mux);
+
+        result.and(deselect)
+    }
+}
+
+impl<A, I2C, Mux> Read<A> for I2cMuxChannel<I2C, Mux>
+where
+    A: AddressMode,
+    I2C: Read<A>,
+    Mux: MuxControl<I2C>,
+{
+    type Error = I2cMuxError<I2C::Error, Mux::Error>;
+
+    fn try_read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.with(|i2c| i2c.try_read(address, buffer))
+    }
+}
+
+impl<A, I2C, Mux> Write<A> for I2cMuxChannel<I2C, Mux>
+where
+    A: AddressMode,
+    I2C: Write<A>,
+    Mux: MuxControl<I2C>,
+{
+    type Error = I2cMuxError<I2C::Error, Mux::Error>;
+
+    fn try_write(&mut self, address: A, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.with(|i2c| i2c.try_write(address, bytes))
+    }
+}
+
+impl<A, I2C, Mux> WriteIter<A> for I2cMuxChannel<I2C, Mux>
+where
+    A: AddressMode,
+    I2C: WriteIter<A>,
+    Mux: MuxControl<I2C>,
+{
+    type Error = I2cMuxError<I2C::Error, Mux::Error>;
+
+    fn try_write_iter<B>(&mut self, address: A, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        self.with(|i2c| i2c.try_write_iter(address, bytes))
+    }
+}
+
+impl<A, I2C, Mux> WriteRead<A> for I2cMuxChannel<I2C, Mux>
+where
+    A: AddressMode,
+    I2C: WriteRead<A>,
+    Mux: MuxControl<I2C>,
+{
+    type Error = I2cMuxError<I2C::Error, Mux::Error>;
+
+    fn try_write_read(
+        &mut self,
+        address: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.with(|i2c| i2c.try_write_read(address, bytes, buffer))
+    }
+}
+
+impl<A, I2C, Mux> WriteIterRead<A> for I2cMuxChannel<I2C, Mux>
+where
+    A: AddressMode,
+    I2C: WriteIterRead<A>,
+    Mux: MuxControl<I2C>,
+{
+    type Error = I2cMuxError<I2C::Error, Mux::Error>;
+
+    fn try_write_iter_read<B>(
+        &mut self,
+        address: A,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        self.with(|i2c| i2c.try_write_iter_read(address, bytes, buffer))
+    }
+}
+
+impl<A, I2C, Mux> Transactional<A> for I2cMuxChannel<I2C, Mux>
+where
+    A: AddressMode,
+    I2C: Transactional<A>,
+    Mux: MuxControl<I2C>,
+{
+    type Error = I2cMuxError<I2C::Error, Mux::Error>;
+
+    fn try_exec<'a>(
+        &mut self,
+        address: A,
+        operations: &mut [Operation<'a>],
+    ) -> Result<(), Self::Error> {
+        self.with(|i2c| i2c.try_exec(address, operations))
+    }
+}
@@ -0,0 +1,142 @@
+//! I2C bus recovery over GPIO pins
+//!
+//! I2C peripherals usually can't generate clock pulses on their own while SDA is held low, so
+//! recovery is done with the SCL and SDA pins temporarily configured as open drain GPIOs.
+//! [`GpioBusClear`] implements [`BusClear`] that way; the pins are returned by
+//! [`destroy`](GpioBusClear::destroy) to be reconfigured for the I2C peripheral.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::recovery::GpioBusClear;
+//! use embedded_hal::blocking::i2c::BusClear;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::delay::DelayUs;
+//! # use embedded_hal::digital::{InputPin, OutputPin};
+//! # struct Pin;
+//! # impl OutputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl InputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_is_high(&self) -> Result<bool, Self::Error> { Ok(true) }
+//! #     fn try_is_low(&self) -> Result<bool, Self::Error> { Ok(false) }
+//! # }
+//! # struct Delay;
+//! # impl DelayUs<u32> for Delay {
+//! #     type Error = Infallible;
+//! #     fn try_delay_us(&mut self, _: u32) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (scl, sda, delay) = (Pin, Pin, Delay);
+//!
+//! // 5 us half period, i.e. 100 kHz clock pulses
+//! let mut recovery = GpioBusClear::new(scl, sda, delay, 5);
+//! recovery.try_clear_bus().unwrap();
+//!
+//! let (scl, sda, delay) = recovery.destroy();
+//! // reconfigure the pins for the I2C peripheral ...
+//! ```
+
+use super::BusClear;
+use crate::blocking::delay::DelayUs;
+use crate::digital::{InputPin, OutputPin};
+
+/// Maximum number of clock pulses needed for a slave to release SDA
+const MAX_PULSES: usize = 9;
+
+wrapper_error! {
+    /// Bus recovery error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum BusClearError<SclError, SdaError, DelayError> {
+        /// Underlying SCL pin error
+        Scl(SclError) => Other, "SCL pin error",
+        /// Underlying SDA pin error
+        Sda(SdaError) => Other, "SDA pin error",
+        /// Underlying delay error
+        Delay(DelayError) => Other, "delay error",
+        /// SDA is still held low after 9 clock pulses
+        SdaStuck => Other, "SDA still held low",
+    }
+
+    impl super::Error => super::ErrorKind
+}
+
+/// Bus recovery using open drain SCL and SDA pins
+///
+/// Both pins must be configured as open drain outputs, with SDA readable as an input. The pins
+/// may be of different types, SDA reporting the same error type as an input and as an output.
+pub struct GpioBusClear<Scl, Sda, Delay> {
+    scl: Scl,
+    sda: Sda,
+    delay: Delay,
+    half_period_us: u32,
+}
+
+impl<Scl, Sda, Delay, SdaError> GpioBusClear<Scl, Sda, Delay>
+where
+    Scl: OutputPin,
+    Sda: OutputPin<Error = SdaError> + InputPin<Error = SdaError>,
+    Delay: DelayUs<u32>,
+{
+    /// Creates a new bus recovery helper
+    ///
+    /// `half_period_us` is the time in microseconds between consecutive clock edges.
+    pub fn new(scl: Scl, sda: Sda, delay: Delay, half_period_us: u32) -> Self {
+        Self {
+            scl,
+            sda,
+            delay,
+            half_period_us,
+        }
+    }
+
+    /// Destroys the helper, returning the pins and delay
+    pub fn destroy(self) -> (Scl, Sda, Delay) {
+        (self.scl, self.sda, self.delay)
+    }
+
+    fn wait(&mut self) -> Result<(), BusClearError<Scl::Error, SdaError, Delay::Error>> {
+        self.delay
+            .try_delay_us(self.half_period_us)
+            .map_err(BusClearError::Delay)
+    }
+}
+
+impl<Scl, Sda, Delay, SdaError> BusClear for GpioBusClear<Scl, Sda, Delay>
+where
+    Scl: OutputPin,
+    Sda: OutputPin<Error = SdaError> + InputPin<Error = SdaError>,
+    Delay: DelayUs<u32>,
+{
+    type Error = BusClearError<Scl::Error, SdaError, Delay::Error>;
+
+    fn try_clear_bus(&mut self) -> Result<(), Self::Error> {
+        self.sda.try_set_high().map_err(BusClearError::Sda)?;
+        self.scl.try_set_high().map_err(BusClearError::Scl)?;
+        self.wait()?;
+
+        let mut pulses = 0;
+        while self.sda.try_is_low().map_err(BusClearError::Sda)? {
+            if pulses == MAX_PULSES {
+                return Err(BusClearError::SdaStuck);
+            }
+            pulses += 1;
+
+            self.scl.try_set_low().map_err(BusClearError::Scl)?;
+            self.wait()?;
+            self.scl.try_set_high().map_err(BusClearError::Scl)?;
+            self.wait()?;
+        }
+
+        // stop condition: SDA rising while SCL is high
+        self.scl.try_set_low().map_err(BusClearError::Scl)?;
+        self.sda.try_set_low().map_err(BusClearError::Sda)?;
+        self.wait()?;
+        self.scl.try_set_high().map_err(BusClearError::Scl)?;
+        self.wait()?;
+        self.sda.try_set_high().map_err(BusClearError::Sda)?;
+        self.wait()
+    }
+}
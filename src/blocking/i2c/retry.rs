@@ -0,0 +1,219 @@
+//! Retrying of unacknowledged I2C operations
+//!
+//! Some devices stop acknowledging their address while busy, e.g. EEPROMs during their internal
+//! write cycle. [`I2cRetry`] repeats an operation whose address was not acknowledged, or that
+//! lost the arbitration, up to a configured number of times, waiting between attempts, and only
+//! reports the error of the last attempt. Other errors, including data bytes that were not
+//! acknowledged and unacknowledged bytes of [unknown](super::NoAcknowledgeSource::Unknown)
+//! kind, are reported immediately as the operation may have had an effect.
+//!
+//! Iterator based operations can't be repeated, so `WriteIter`, `WriteIterRead` and
+//! `TransactionalIter` are not implemented.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::retry::I2cRetry;
+//! use embedded_hal::blocking::i2c::{Write, WriteRead};
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::delay::DelayUs;
+//! # struct I2c;
+//! # impl Write for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write(&mut self, _: u8, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl WriteRead for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write_read(&mut self, _: u8, _: &[u8], _: &mut [u8]) -> Result<(), Self::Error> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! # struct Delay;
+//! # impl DelayUs<u32> for Delay {
+//! #     type Error = Infallible;
+//! #     fn try_delay_us(&mut self, _: u32) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (i2c, delay) = (I2c, Delay);
+//!
+//! // the write cycle of the EEPROM takes up to 5 ms
+//! let mut eeprom = I2cRetry::new(i2c, delay, 10, 500);
+//! eeprom.try_write(0x50, &[0x00, 0x10, 0xAB]).unwrap();
+//!
+//! let mut byte = [0];
+//! eeprom.try_write_read(0x50, &[0x00, 0x10], &mut byte).unwrap();
+//! ```
+
+use core::convert::Infallible;
+use core::fmt::Debug;
+
+use super::{
+    AddressMode, Error, ErrorKind, NoAcknowledgeSource, Operation, Read, Transactional, Write,
+    WriteRead,
+};
+use crate::blocking::delay::DelayUs;
+
+/// Wraps a blocking I2C implementation, retrying operations that were not acknowledged
+pub struct I2cRetry<I2C, Delay> {
+    i2c: I2C,
+    delay: Delay,
+    retries: usize,
+    delay_us: u32,
+}
+
+wrapper_error! {
+    /// Error type of the retrying wrappers, combining I2C, delay and random number generator
+    /// errors
+    ///
+    /// The random number generator is only used by
+    /// [`MultiMaster`](super::multi_master::MultiMaster).
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum I2cRetryError<I2cError, DelayError, RngError = Infallible> {
+        /// Error of the last attempt
+        I2c(I2cError) => forward, "I2C error",
+        /// Underlying delay error
+        Delay(DelayError) => Other, "delay error",
+        /// Underlying random number generator error
+        Rng(RngError) => Other, "random number generator error",
+    }
+
+    impl Error => ErrorKind where I2cError: Error
+}
+
+/// Runs `f` on `i2c`, running it again after `backoff` as long as it fails with an error kind
+/// accepted by `retryable` and `retries` remain
+pub(super) fn retry<I2C, R, E, DelayError, RngError>(
+    i2c: &mut I2C,
+    mut retries: usize,
+    retryable: fn(ErrorKind) -> bool,
+    mut backoff: impl FnMut() -> Result<(), I2cRetryError<E, DelayError, RngError>>,
+    mut f: impl FnMut(&mut I2C) -> Result<R, E>,
+) -> Result<R, I2cRetryError<E, DelayError, RngError>>
+where
+    E: Error,
+{
+    loop {
+        match f(i2c) {
+            Ok(r) => return Ok(r),
+            Err(e) if retries == 0 || !retryable(e.kind()) => return Err(I2cRetryError::I2c(e)),
+            Err(_) => {
+                retries -= 1;
+                backoff()?;
+            }
+        }
+    }
+}
+
+impl<I2C, Delay> I2cRetry<I2C, Delay>
+where
+    Delay: DelayUs<u32>,
+{
+    /// Creates a new wrapper retrying unacknowledged operations up to `retries` times, waiting
+    /// `delay_us` microseconds before each retry
+    pub fn new(i2c: I2C, delay: Delay, retries: usize, delay_us: u32) -> Self {
+        Self {
+            i2c,
+            delay,
+            retries,
+            delay_us,
+        }
+    }
+
+    /// Destroys the wrapper, returning the I2C bus and delay
+    pub fn destroy(self) -> (I2C, Delay) {
+        (self.i2c, self.delay)
+    }
+
+    /// Runs `f` on the inner bus, running it again after the configured delay as long as it
+    /// fails with an unacknowledged address or [`ErrorKind::ArbitrationLoss`] and retries remain
+    pub fn retry<R, E, F>(&mut self, f: F) -> Result<R, I2cRetryError<E, Delay::Error>>
+    where
+        E: Error,
+        F: FnMut(&mut I2C) -> Result<R, E>,
+    {
+        let Self {
+            i2c,
+            delay,
+            retries,
+            delay_us,
+        } = self;
+
+        retry(
+            i2c,
+            *retries,
+            |kind| {
+                matches!(
+                    kind,
+                    ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)
+                        | ErrorKind::ArbitrationLoss
+                )
+            },
+            || delay.try_delay_us(*delay_us).map_err(I2cRetryError::Delay),
+            f,
+        )
+    }
+}
+
+impl<A, I2C, Delay> Read<A> for I2cRetry<I2C, Delay>
+where
+    A: AddressMode + Copy,
+    I2C: Read<A>,
+    I2C::Error: Error,
+    Delay: DelayUs<u32>,
+{
+    type Error = I2cRetryError<I2C::Error, Delay::Error>;
+
+    fn try_read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.retry(|i2c| i2c.try_read(address, buffer))
+    }
+}
+
+impl<A, I2C, Delay> Write<A> for I2cRetry<I2C, Delay>
+where
+    A: AddressMode + Copy,
+    I2C: Write<A>,
+    I2C::Error: Error,
+    Delay: DelayUs<u32>,
+{
+    type Error = I2cRetryError<I2C::Error, Delay::Error>;
+
+    fn try_write(&mut self, address: A, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.retry(|i2c| i2c.try_write(address, bytes))
+    }
+}
+
+impl<A, I2C, Delay> WriteRead<A> for I2cRetry<I2C, Delay>
+where
+    A: AddressMode + Copy,
+    I2C: WriteRead<A>,
+    I2C::Error: Error,
+    Delay: DelayUs<u32>,
+{
+    type Error = I2cRetryError<I2C::Error, Delay::Error>;
+
+    fn try_write_read(
+        &mut self,
+        address: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.retry(|i2c| i2c.try_write_read(address, bytes, buffer))
+    }
+}
+
+impl<A, I2C, Delay> Transactional<A> for I2cRetry<I2C, Delay>
+where
+    A: AddressMode + Copy,
+    I2C: Transactional<A>,
+    I2C::Error: Error,
+    Delay: DelayUs<u32>,
+{
+    type Error = I2cRetryError<I2C::Error, Delay::Error>;
+
+    fn try_exec<'a>(
+        &mut self,
+        address: A,
+        operations: &mut [Operation<'a>],
+    ) -> Result<(), Self::Error> {
+        self.retry(|i2c| i2c.try_exec(address, operations))
+    }
+}
@@ -0,0 +1,105 @@
+//! Scanning an I2C bus for devices
+//!
+//! [`Scan`] extends every blocking [`Write`] implementation with
+//! [`try_scan`](Scan::try_scan), which probes each address outside the reserved ranges with an
+//! empty write and reports the ones that were acknowledged.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::scan::Scan;
+//! # use embedded_hal::blocking::i2c::{self, ErrorKind, NoAcknowledgeSource, Write};
+//! # #[derive(Debug)]
+//! # struct Nack;
+//! # impl i2c::Error for Nack {
+//! #     fn kind(&self) -> ErrorKind { ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address) }
+//! # }
+//! # struct I2c;
+//! # impl Write for I2c {
+//! #     type Error = Nack;
+//! #     fn try_write(&mut self, address: u8, _: &[u8]) -> Result<(), Self::Error> {
+//! #         if address == 0x48 || address == 0x68 { Ok(()) } else { Err(Nack) }
+//! #     }
+//! # }
+//! # let mut i2c = I2c;
+//!
+//! let devices = i2c.try_scan().unwrap();
+//! assert!(devices.contains(0x68));
+//!
+//! for address in devices.iter() {
+//!     println!("found device at {:#04x}", address);
+//! }
+//! # assert_eq!(devices.iter().collect::<Vec<_>>(), [0x48, 0x68]);
+//! ```
+
+use super::{Error, ErrorKind, SevenBitAddress, Write};
+
+/// Lowest address probed by [`Scan::try_scan`], lower addresses are reserved
+pub const FIRST_ADDRESS: SevenBitAddress = 0x08;
+
+/// Highest address probed by [`Scan::try_scan`], higher addresses are reserved
+pub const LAST_ADDRESS: SevenBitAddress = 0x77;
+
+/// Set of 7-bit addresses that acknowledged a probe
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Devices {
+    bits: u128,
+}
+
+impl Devices {
+    /// Returns whether a device acknowledged `address`
+    pub fn contains(&self, address: SevenBitAddress) -> bool {
+        address < 128 && self.bits & (1 << address) != 0
+    }
+
+    /// Returns the number of devices found
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Returns whether no device was found
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns an iterator over the addresses of the devices found, in increasing order
+    pub fn iter(&self) -> impl Iterator<Item = SevenBitAddress> {
+        let bits = self.bits;
+        (0..128).filter(move |address| bits & (1 << address) != 0)
+    }
+
+    fn insert(&mut self, address: SevenBitAddress) {
+        self.bits |= 1 << address;
+    }
+}
+
+/// Bus scan, implemented for all blocking [`Write`] implementations
+pub trait Scan: Write {
+    /// Probes all the addresses from [`FIRST_ADDRESS`] to [`LAST_ADDRESS`]
+    ///
+    /// Each address is probed with an empty write, which most devices acknowledge without any
+    /// side effect. Errors other than [`ErrorKind::NoAcknowledge`] abort the scan.
+    fn try_scan(&mut self) -> Result<Devices, Self::Error>;
+}
+
+impl<I2C> Scan for I2C
+where
+    I2C: Write,
+    I2C::Error: Error,
+{
+    fn try_scan(&mut self) -> Result<Devices, Self::Error> {
+        let mut devices = Devices::default();
+
+        for address in FIRST_ADDRESS..=LAST_ADDRESS {
+            match self.try_write(address, &[]) {
+                Ok(()) => devices.insert(address),
+                Err(e) => match e.kind() {
+                    ErrorKind::NoAcknowledge(_) => {}
+                    _ => return Err(e),
+                },
+            }
+        }
+
+        Ok(devices)
+    }
+}
@@ -0,0 +1,237 @@
+//! Sharing a single I2C bus between several devices
+//!
+//! A [`SharedBus`] takes ownership of an I2C bus and hands out any number of [`BusProxy`]
+//! handles implementing the blocking I2C traits, so drivers for different devices can each own
+//! a handle while using the same bus. Each call borrows the bus through a `RefCell` for its
+//! duration, so the handles can only be used from a single execution context.
+//!
+//! With the `critical-section` feature, [`CriticalSectionBus`] runs each call in a critical
+//! section instead, so it can be stored in a `static` and shared with interrupt handlers.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::shared::SharedBus;
+//! use embedded_hal::blocking::i2c::{Write, WriteRead};
+//! # use core::convert::Infallible;
+//! # struct I2c;
+//! # impl Write for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write(&mut self, _: u8, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl WriteRead for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write_read(&mut self, _: u8, _: &[u8], _: &mut [u8]) -> Result<(), Self::Error> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let i2c = I2c;
+//!
+//! let bus = SharedBus::new(i2c);
+//!
+//! let mut thermometer = bus.acquire();
+//! let mut display = bus.acquire();
+//!
+//! let mut temperature = [0; 2];
+//! thermometer.try_write_read(0x48, &[0x00], &mut temperature).unwrap();
+//! display.try_write(0x3C, &[0x00, 0xAF]).unwrap();
+//! ```
+
+use core::cell::RefCell;
+
+use super::{
+    Operation, Read, SevenBitAddress, TenBitAddress, Transactional, TransactionalIter, Write,
+    WriteIter, WriteIterRead, WriteRead,
+};
+
+/// I2C bus shared between several devices
+pub struct SharedBus<I2C> {
+    i2c: RefCell<I2C>,
+}
+
+impl<I2C> SharedBus<I2C> {
+    /// Creates a new shared bus from the provided I2C bus
+    pub fn new(i2c: I2C) -> Self {
+        Self {
+            i2c: RefCell::new(i2c),
+        }
+    }
+
+    /// Creates a new handle to the bus
+    pub fn acquire(&self) -> BusProxy<'_, I2C> {
+        BusProxy { i2c: &self.i2c }
+    }
+
+    /// Destroys the shared bus, returning the I2C bus
+    pub fn destroy(self) -> I2C {
+        self.i2c.into_inner()
+    }
+}
+
+/// Borrowed access to a [`SharedBus`]
+pub struct BusProxy<'a, I2C> {
+    i2c: &'a RefCell<I2C>,
+}
+
+impl<'a, I2C> BusProxy<'a, I2C> {
+    fn with<R>(&mut self, f: impl FnOnce(&mut I2C) -> R) -> R {
+        f(&mut self.i2c.borrow_mut())
+    }
+}
+
+/// I2C bus shared between several devices, possibly in different interrupt handlers
+///
+/// This is only available when the `critical-section` feature is enabled.
+#[cfg(feature = "critical-section")]
+pub struct CriticalSectionBus<I2C> {
+    i2c: critical_section::Mutex<RefCell<I2C>>,
+}
+
+#[cfg(feature = "critical-section")]
+impl<I2C> CriticalSectionBus<I2C> {
+    /// Creates a new shared bus from the provided I2C bus
+    pub const fn new(i2c: I2C) -> Self {
+        Self {
+            i2c: critical_section::Mutex::new(RefCell::new(i2c)),
+        }
+    }
+
+    /// Creates a new handle to the bus
+    pub fn acquire(&self) -> CriticalSectionProxy<'_, I2C> {
+        CriticalSectionProxy { i2c: &self.i2c }
+    }
+
+    /// Destroys the shared bus, returning the I2C bus
+    pub fn destroy(self) -> I2C {
+        self.i2c.into_inner().into_inner()
+    }
+}
+
+/// Borrowed access to a [`CriticalSectionBus`]
+///
+/// Each call to one of the blocking I2C traits runs in a critical section.
+#[cfg(feature = "critical-section")]
+pub struct CriticalSectionProxy<'a, I2C> {
+    i2c: &'a critical_section::Mutex<RefCell<I2C>>,
+}
+
+#[cfg(feature = "critical-section")]
+impl<'a, I2C> CriticalSectionProxy<'a, I2C> {
+    fn with<R>(&mut self, f: impl FnOnce(&mut I2C) -> R) -> R {
+        critical_section::with(|cs| f(&mut self.i2c.borrow_ref_mut(cs)))
+    }
+}
+
+macro_rules! impl_proxy {
+    ($proxy:ident; $($A:ty),*) => {
+        $(
+            impl<'a, I2C> Read<$A> for $proxy<'a, I2C>
+            where
+                I2C: Read<$A>,
+            {
+                type Error = I2C::Error;
+
+                fn try_read(&mut self, address: $A, buffer: &mut [u8]) -> Result<(), Self::Error> {
+                    self.with(|i2c| i2c.try_read(address, buffer))
+                }
+            }
+
+            impl<'a, I2C> Write<$A> for $proxy<'a, I2C>
+            where
+                I2C: Write<$A>,
+            {
+                type Error = I2C::Error;
+
+                fn try_write(&mut self, address: $A, bytes: &[u8]) -> Result<(), Self::Error> {
+                    self.with(|i2c| i2c.try_write(address, bytes))
+                }
+            }
+
+            impl<'a, I2C> WriteIter<$A> for $proxy<'a, I2C>
+            where
+                I2C: WriteIter<$A>,
+            {
+                type Error = I2C::Error;
+
+                fn try_write_iter<B>(&mut self, address: $A, bytes: B) -> Result<(), Self::Error>
+                where
+                    B: IntoIterator<Item = u8>,
+                {
+                    self.with(|i2c| i2c.try_write_iter(address, bytes))
+                }
+            }
+
+            impl<'a, I2C> WriteRead<$A> for $proxy<'a, I2C>
+            where
+                I2C: WriteRead<$A>,
+            {
+                type Error = I2C::Error;
+
+                fn try_write_read(
+                    &mut self,
+                    address: $A,
+                    bytes: &[u8],
+                    buffer: &mut [u8],
+                ) -> Result<(), Self::Error> {
+                    self.with(|i2c| i2c.try_write_read(address, bytes, buffer))
+                }
+            }
+
+            impl<'a, I2C> WriteIterRead<$A> for $proxy<'a, I2C>
+            where
+                I2C: WriteIterRead<$A>,
+            {
+                type Error = I2C::Error;
+
+                fn try_write_iter_read<B>(
+                    &mut self,
+                    address: $A,
+                    bytes: B,
+                    buffer: &mut [u8],
+                ) -> Result<(), Self::Error>
+                where
+                    B: IntoIterator<Item = u8>,
+                {
+                    self.with(|i2c| i2c.try_write_iter_read(address, bytes, buffer))
+                }
+            }
+
+            impl<'a, I2C> Transactional<$A> for $proxy<'a, I2C>
+            where
+                I2C: Transactional<$A>,
+            {
+                type Error = I2C::Error;
+
+                fn try_exec<'o>(
+                    &mut self,
+                    address: $A,
+                    operations: &mut [Operation<'o>],
+                ) -> Result<(), Self::Error> {
+                    self.with(|i2c| i2c.try_exec(address, operations))
+                }
+            }
+
+            impl<'a, I2C> TransactionalIter<$A> for $proxy<'a, I2C>
+            where
+                I2C: TransactionalIter<$A>,
+            {
+                type Error = I2C::Error;
+
+                fn try_exec_iter<'o, O>(
+                    &mut self,
+                    address: $A,
+                    operations: O,
+                ) -> Result<(), Self::Error>
+                where
+                    O: IntoIterator<Item = Operation<'o>>,
+                {
+                    self.with(|i2c| i2c.try_exec_iter(address, operations))
+                }
+            }
+        )*
+    };
+}
+
+impl_proxy!(BusProxy; SevenBitAddress, TenBitAddress);
+#[cfg(feature = "critical-section")]
+impl_proxy!(CriticalSectionProxy; SevenBitAddress, TenBitAddress);
@@ -6,6 +6,9 @@
 
 pub mod delay;
 pub mod i2c;
+pub mod qspi;
+pub mod register;
 pub mod rng;
 pub mod serial;
+pub mod smbus;
 pub mod spi;
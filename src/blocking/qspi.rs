@@ -0,0 +1,131 @@
+//! Blocking Quad SPI API
+//!
+//! Quad SPI (QSPI) peripherals, as commonly used to talk to serial NOR flash, split each
+//! transaction into up to five phases, each of which may be transferred over one, two or four
+//! data lines:
+//!
+//! ``` text
+//! | instruction | address | alternate bytes | dummy cycles | data |
+//! ```
+//!
+//! Every phase except the data phase is optional. The phases are described by a [`Command`],
+//! while the data phase is supplied through the [`Read`] and [`Write`] traits.
+//!
+//! ## Examples
+//!
+//! Fast read quad output (`0x6B`) from a W25Q-style flash:
+//!
+//! ```
+//! use embedded_hal::blocking::qspi::{Address, AddressSize, Command, Instruction, Read, Width};
+//!
+//! fn fast_read_quad<Q: Read>(qspi: &mut Q, addr: u32, buf: &mut [u8]) -> Result<(), Q::Error> {
+//!     let command = Command {
+//!         instruction: Some(Instruction {
+//!             width: Width::Single,
+//!             value: 0x6B,
+//!         }),
+//!         address: Some(Address {
+//!             width: Width::Single,
+//!             size: AddressSize::ThreeBytes,
+//!             value: addr,
+//!         }),
+//!         alternate_bytes: None,
+//!         dummy_cycles: 8,
+//!         data_width: Width::Quad,
+//!     };
+//!
+//!     qspi.try_read(&command, buf)
+//! }
+//! ```
+
+/// Number of data lines used to transfer a phase
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Width {
+    /// One data line (standard SPI)
+    Single,
+    /// Two data lines
+    Dual,
+    /// Four data lines
+    Quad,
+}
+
+/// Size of the address phase
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AddressSize {
+    /// 8-bit address
+    OneByte,
+    /// 16-bit address
+    TwoBytes,
+    /// 24-bit address
+    ThreeBytes,
+    /// 32-bit address
+    FourBytes,
+}
+
+/// Instruction phase
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    /// Number of data lines used for the instruction
+    pub width: Width,
+    /// Instruction opcode
+    pub value: u8,
+}
+
+/// Address phase
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Address {
+    /// Number of data lines used for the address
+    pub width: Width,
+    /// Number of address bytes to send
+    pub size: AddressSize,
+    /// Address, only the lower `size` bytes are sent
+    pub value: u32,
+}
+
+/// Alternate bytes phase
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AlternateBytes<'a> {
+    /// Number of data lines used for the alternate bytes
+    pub width: Width,
+    /// Alternate bytes to send, e.g. the "continuous read" mode bits
+    pub bytes: &'a [u8],
+}
+
+/// Description of the phases preceding the data phase of a QSPI transaction
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Command<'a> {
+    /// Instruction phase, skipped if `None`
+    pub instruction: Option<Instruction>,
+    /// Address phase, skipped if `None`
+    pub address: Option<Address>,
+    /// Alternate bytes phase, skipped if `None`
+    pub alternate_bytes: Option<AlternateBytes<'a>>,
+    /// Number of clock cycles to wait between the preceding phases and the data phase
+    pub dummy_cycles: u8,
+    /// Number of data lines used for the data phase
+    pub data_width: Width,
+}
+
+/// Blocking QSPI read
+pub trait Read {
+    /// Error type
+    type Error;
+
+    /// Executes `command` and then reads enough bytes from the slave to fill `buffer` in the
+    /// data phase
+    ///
+    /// If `buffer` is empty no data phase is executed.
+    fn try_read(&mut self, command: &Command<'_>, buffer: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+/// Blocking QSPI write
+pub trait Write {
+    /// Error type
+    type Error;
+
+    /// Executes `command` and then sends `bytes` to the slave in the data phase
+    ///
+    /// If `bytes` is empty no data phase is executed, which is how command-only instructions
+    /// like "write enable" are issued.
+    fn try_write(&mut self, command: &Command<'_>, bytes: &[u8]) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,147 @@
+//! Blocking register access
+//!
+//! Most sensors and peripheral ICs expose a map of registers, accessed by sending a register
+//! address followed by the data to write, or by sending the address and then reading the data.
+//! [`RegisterRead`] and [`RegisterWrite`] abstract over the bus used to do so, allowing the
+//! register level logic of a driver to be written once for devices available with both an I2C
+//! and an SPI interface.
+//!
+//! Both traits are implemented for 8 and 16-bit register addresses, the latter being sent most
+//! significant byte first:
+//!
+//! - by [`I2cDevice`], sending the register address in the same write as the data, or
+//!   followed by a repeated start before reading.
+//! - by SPI buses implementing [`ManagedCs`], sending the register address and the data in a
+//!   single transaction.
+//!
+//! Devices that signal the direction in the register address, e.g. by setting its most
+//! significant bit for reads over SPI, need the driver to include it in `register`.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::register::{RegisterRead, RegisterWrite};
+//!
+//! const CTRL: u8 = 0x20;
+//! const OUT: u8 = 0x28;
+//!
+//! /// Accelerometer with identical register maps over I2C and SPI
+//! pub struct Accelerometer<B> {
+//!     bus: B,
+//! }
+//!
+//! impl<B, E> Accelerometer<B>
+//! where
+//!     B: RegisterRead<Error = E> + RegisterWrite<Error = E>,
+//! {
+//!     pub fn enable(&mut self) -> Result<(), E> {
+//!         self.bus.try_write_register(CTRL, &[0x57])
+//!     }
+//!
+//!     pub fn acceleration(&mut self) -> Result<[i16; 3], E> {
+//!         let mut buf = [0; 6];
+//!         self.bus.try_read_register(OUT, &mut buf)?;
+//!
+//!         Ok([
+//!             i16::from_le_bytes([buf[0], buf[1]]),
+//!             i16::from_le_bytes([buf[2], buf[3]]),
+//!             i16::from_le_bytes([buf[4], buf[5]]),
+//!         ])
+//!     }
+//! }
+//! ```
+
+use super::i2c::device::I2cDevice;
+use super::{i2c, spi};
+use spi::ManagedCs;
+
+/// Blocking register read
+pub trait RegisterRead<R = u8> {
+    /// Error type
+    type Error;
+
+    /// Reads enough bytes to fill `buffer` from the device, starting at `register`
+    fn try_read_register(&mut self, register: R, buffer: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+/// Blocking register write
+pub trait RegisterWrite<R = u8> {
+    /// Error type
+    type Error;
+
+    /// Writes `data` to the device, starting at `register`
+    fn try_write_register(&mut self, register: R, data: &[u8]) -> Result<(), Self::Error>;
+}
+
+macro_rules! impl_register {
+    ($($R:ty),*) => {
+        $(
+            impl<I2C> RegisterRead<$R> for I2cDevice<I2C>
+            where
+                I2C: i2c::WriteRead,
+            {
+                type Error = I2C::Error;
+
+                fn try_read_register(
+                    &mut self,
+                    register: $R,
+                    buffer: &mut [u8],
+                ) -> Result<(), Self::Error> {
+                    self.try_write_read(&register.to_be_bytes(), buffer)
+                }
+            }
+
+            impl<I2C> RegisterWrite<$R> for I2cDevice<I2C>
+            where
+                I2C: i2c::Transactional,
+            {
+                type Error = I2C::Error;
+
+                fn try_write_register(&mut self, register: $R, data: &[u8]) -> Result<(), Self::Error> {
+                    let address = self.address();
+                    self.bus().try_exec(
+                        address,
+                        &mut [
+                            i2c::Operation::Write(&register.to_be_bytes()),
+                            i2c::Operation::Write(data),
+                        ],
+                    )
+                }
+            }
+
+            impl<Spi> RegisterRead<$R> for Spi
+            where
+                Spi: ManagedCs + spi::Transactional<u8>,
+            {
+                type Error = Spi::Error;
+
+                fn try_read_register(
+                    &mut self,
+                    register: $R,
+                    buffer: &mut [u8],
+                ) -> Result<(), Self::Error> {
+                    self.try_exec(&mut [
+                        spi::Operation::Write(&register.to_be_bytes()),
+                        spi::Operation::Read(buffer),
+                    ])
+                }
+            }
+
+            impl<Spi> RegisterWrite<$R> for Spi
+            where
+                Spi: ManagedCs + spi::Transactional<u8>,
+            {
+                type Error = Spi::Error;
+
+                fn try_write_register(&mut self, register: $R, data: &[u8]) -> Result<(), Self::Error> {
+                    self.try_exec(&mut [
+                        spi::Operation::Write(&register.to_be_bytes()),
+                        spi::Operation::Write(data),
+                    ])
+                }
+            }
+        )*
+    };
+}
+
+impl_register!(u8, u16);
@@ -0,0 +1,207 @@
+//! Blocking serial API
+
+pub mod rs485;
+pub mod tee;
+pub mod timeout;
+
+/// Read half of a serial interface (blocking variant)
+pub trait Read<Word> {
+    /// The type of error that can occur when reading
+    type Error;
+
+    /// Reads words until `buffer` is full, blocking until they all have been received
+    ///
+    /// If an error occurs, the contents of `buffer` are unspecified.
+    fn try_read_exact(&mut self, buffer: &mut [Word]) -> Result<(), Self::Error>;
+}
+
+/// Delimited reads over a blocking serial interface
+///
+/// This is implemented for all implementers of [`Read`] for bytes, for text protocols like AT
+/// commands or NMEA sentences.
+///
+/// ```
+/// use embedded_hal::blocking::serial::{ReadUntil, Write};
+/// use embedded_hal::loopback;
+///
+/// let mut modem = loopback::Serial::<u8, 32>::new();
+/// modem.try_bwrite_all(b"+CSQ: 21,0\r\nOK\r\n").unwrap();
+///
+/// let mut line = [0; 16];
+/// let len = modem.try_read_line(&mut line).unwrap();
+/// assert_eq!(&line[..len], b"+CSQ: 21,0");
+/// let len = modem.try_read_line(&mut line).unwrap();
+/// assert_eq!(&line[..len], b"OK");
+/// ```
+pub trait ReadUntil {
+    /// The type of error that can occur when reading
+    type Error;
+
+    /// Reads bytes into `buffer` until `delimiter` is received, returning how many were read
+    ///
+    /// The count includes the delimiter, which is stored in `buffer` as well. If `buffer` fills
+    /// up before the delimiter is received, the following bytes up to the delimiter are
+    /// discarded and [`ReadUntilError::Overflow`] is returned, so the next read starts after
+    /// the delimiter.
+    fn try_read_until(
+        &mut self,
+        delimiter: u8,
+        buffer: &mut [u8],
+    ) -> Result<usize, ReadUntilError<Self::Error>>;
+
+    /// Reads a line terminated by `\n` or `\r\n` into `buffer`, returning its length
+    ///
+    /// The terminator is stored in `buffer` but not included in the length. Lines longer than
+    /// `buffer` are handled as by [`try_read_until`](ReadUntil::try_read_until).
+    fn try_read_line(&mut self, buffer: &mut [u8]) -> Result<usize, ReadUntilError<Self::Error>> {
+        let len = self.try_read_until(b'\n', buffer)? - 1;
+        if len > 0 && buffer[len - 1] == b'\r' {
+            Ok(len - 1)
+        } else {
+            Ok(len)
+        }
+    }
+}
+
+impl<S> ReadUntil for S
+where
+    S: Read<u8> + ?Sized,
+{
+    type Error = S::Error;
+
+    fn try_read_until(
+        &mut self,
+        delimiter: u8,
+        buffer: &mut [u8],
+    ) -> Result<usize, ReadUntilError<Self::Error>> {
+        let mut byte = [0];
+        for count in 1..=buffer.len() {
+            self.try_read_exact(&mut byte)
+                .map_err(ReadUntilError::Serial)?;
+            buffer[count - 1] = byte[0];
+            if byte[0] == delimiter {
+                return Ok(count);
+            }
+        }
+
+        loop {
+            self.try_read_exact(&mut byte)
+                .map_err(ReadUntilError::Serial)?;
+            if byte[0] == delimiter {
+                break;
+            }
+        }
+
+        Err(ReadUntilError::Overflow)
+    }
+}
+
+wrapper_error! {
+    /// Delimited read error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum ReadUntilError<E> {
+        /// Underlying serial error
+        Serial(E) => forward, "serial error",
+        /// The buffer filled up before the delimiter was received
+        Overflow => Other, "delimiter not found within the buffer",
+    }
+
+    impl crate::serial::Error => crate::serial::ErrorKind where E: crate::serial::Error
+}
+
+/// Blocking serial read
+pub mod read {
+    /// Marker trait to opt into default blocking read implementation
+    ///
+    /// Implementers of [`serial::Read`] can implement this marker trait
+    /// for their type. Doing so will automatically provide the default
+    /// implementation of [`blocking::serial::Read`] for the type.
+    ///
+    /// ```
+    /// use embedded_hal::blocking::serial::{read, Read};
+    /// # use core::convert::Infallible;
+    /// # struct Uart;
+    /// # impl embedded_hal::serial::Read<u8> for Uart {
+    /// #     type Error = Infallible;
+    /// #     fn try_read(&mut self) -> nb::Result<u8, Self::Error> { Ok(0x42) }
+    /// # }
+    ///
+    /// impl read::Default<u8> for Uart {}
+    ///
+    /// let mut uart = Uart;
+    /// let mut response = [0; 4];
+    /// uart.try_read_exact(&mut response).unwrap();
+    /// ```
+    ///
+    /// [`serial::Read`]: ../../serial/trait.Read.html
+    /// [`blocking::serial::Read`]: ../trait.Read.html
+    pub trait Default<Word>: crate::serial::Read<Word> {}
+
+    impl<S, Word> crate::blocking::serial::Read<Word> for S
+    where
+        S: Default<Word>,
+    {
+        type Error = S::Error;
+
+        fn try_read_exact(&mut self, buffer: &mut [Word]) -> Result<(), Self::Error> {
+            for word in buffer {
+                *word = nb::block!(self.try_read())?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Write half of a serial interface (blocking variant)
+pub trait Write<Word> {
+    /// The type of error that can occur when writing
+    type Error;
+
+    /// Writes a slice, blocking until everything has been written
+    ///
+    /// An implementation can choose to buffer the write, returning `Ok(())`
+    /// after the complete slice has been written to a buffer, but before all
+    /// words have been sent via the serial interface. To make sure that
+    /// everything has been sent, call [`try_bflush`] after this function returns.
+    ///
+    /// [`try_bflush`]: #tymethod.bflush
+    fn try_bwrite_all(&mut self, buffer: &[Word]) -> Result<(), Self::Error>;
+
+    /// Block until the serial interface has sent all buffered words
+    fn try_bflush(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Blocking serial write
+pub mod write {
+    /// Marker trait to opt into default blocking write implementation
+    ///
+    /// Implementers of [`serial::Write`] can implement this marker trait
+    /// for their type. Doing so will automatically provide the default
+    /// implementation of [`blocking::serial::Write`] for the type.
+    ///
+    /// [`serial::Write`]: ../../serial/trait.Write.html
+    /// [`blocking::serial::Write`]: ../trait.Write.html
+    pub trait Default<Word>: crate::serial::Write<Word> {}
+
+    impl<S, Word> crate::blocking::serial::Write<Word> for S
+    where
+        S: Default<Word>,
+        Word: Clone,
+    {
+        type Error = S::Error;
+
+        fn try_bwrite_all(&mut self, buffer: &[Word]) -> Result<(), Self::Error> {
+            for word in buffer {
+                nb::block!(self.try_write(word.clone()))?;
+            }
+
+            Ok(())
+        }
+
+        fn try_bflush(&mut self) -> Result<(), Self::Error> {
+            nb::block!(self.try_flush())?;
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! RS-485 transceivers
+//!
+//! Half-duplex RS-485 transceivers have a driver enable (DE) input, often tied to the inverted
+//! receiver enable (RE) input, selecting whether they transmit or listen on the bus. [`Rs485`]
+//! drives that pin around each blocking write: it enables the driver, writes the words, waits for
+//! them to be sent with `try_bflush` and releases the bus again.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::serial::rs485::Rs485;
+//! use embedded_hal::blocking::serial::{Read, Write};
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::delay::DelayUs;
+//! # use embedded_hal::digital::OutputPin;
+//! # struct Uart;
+//! # impl embedded_hal::blocking::serial::Write<u8> for Uart {
+//! #     type Error = Infallible;
+//! #     fn try_bwrite_all(&mut self, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_bflush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl embedded_hal::blocking::serial::Read<u8> for Uart {
+//! #     type Error = Infallible;
+//! #     fn try_read_exact(&mut self, _: &mut [u8]) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # struct Pin;
+//! # impl OutputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # struct Delay;
+//! # impl DelayUs<u32> for Delay {
+//! #     type Error = Infallible;
+//! #     fn try_delay_us(&mut self, _: u32) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (uart, de, delay) = (Uart, Pin, Delay);
+//!
+//! // 10 us for the transceiver to switch direction
+//! let mut bus = Rs485::new(uart, de, delay, 10).unwrap();
+//!
+//! // Modbus RTU request, then the response of the slave
+//! bus.try_bwrite_all(&[0x01, 0x03, 0x00, 0x00, 0x00, 0x01, 0x84, 0x0A]).unwrap();
+//! let mut response = [0; 7];
+//! bus.try_read_exact(&mut response).unwrap();
+//! ```
+
+use super::{Read, Write};
+use crate::blocking::delay::DelayUs;
+use crate::digital::OutputPin;
+
+wrapper_error! {
+    /// RS-485 error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Rs485Error<SerialError, PinError, DelayError> {
+        /// Underlying serial error
+        Serial(SerialError) => forward, "serial error",
+        /// Underlying pin error
+        Pin(PinError) => Other, "pin error",
+        /// Underlying delay error
+        Delay(DelayError) => Other, "delay error",
+    }
+
+    impl crate::serial::Error => crate::serial::ErrorKind
+    where
+        SerialError: crate::serial::Error
+}
+
+/// Half-duplex RS-485 bus driving the DE pin of its transceiver
+///
+/// The DE pin is driven high while transmitting.
+pub struct Rs485<S, P, Delay> {
+    serial: S,
+    de: P,
+    delay: Delay,
+    turnaround_us: u32,
+}
+
+impl<S, P, Delay> Rs485<S, P, Delay>
+where
+    P: OutputPin,
+    Delay: DelayUs<u32>,
+{
+    /// Creates a new RS-485 bus, releasing it
+    ///
+    /// `turnaround_us` is the time in microseconds the transceiver needs to switch between
+    /// receiving and transmitting, waited for after enabling and before releasing the driver.
+    pub fn new(serial: S, mut de: P, delay: Delay, turnaround_us: u32) -> Result<Self, P::Error> {
+        de.try_set_low()?;
+
+        Ok(Self {
+            serial,
+            de,
+            delay,
+            turnaround_us,
+        })
+    }
+
+    /// Destroys the bus, returning the serial interface, pin and delay
+    pub fn destroy(self) -> (S, P, Delay) {
+        (self.serial, self.de, self.delay)
+    }
+
+    fn write<Word, E>(
+        &mut self,
+        buffer: &[Word],
+    ) -> Result<(), Rs485Error<E, P::Error, Delay::Error>>
+    where
+        S: Write<Word, Error = E>,
+    {
+        self.de.try_set_high().map_err(Rs485Error::Pin)?;
+
+        let Self { serial, delay, .. } = self;
+        let result = delay
+            .try_delay_us(self.turnaround_us)
+            .map_err(Rs485Error::Delay)
+            .and_then(|()| {
+                serial
+                    .try_bwrite_all(buffer)
+                    .and_then(|()| serial.try_bflush())
+                    .map_err(Rs485Error::Serial)
+            });
+
+        // release the bus even if the write or a delay failed, so the other nodes can transmit,
+        // reporting the first error
+        let release = self
+            .delay
+            .try_delay_us(self.turnaround_us)
+            .map_err(Rs485Error::Delay);
+        let released = self.de.try_set_low().map_err(Rs485Error::Pin);
+
+        result.and(release).and(released)
+    }
+}
+
+// implemented for each word type, as a generic implementation would conflict with the
+// `write::Default` and `read::Default` blanket implementations
+macro_rules! impl_serial {
+    ($($Word:ty),*) => {
+        $(
+            impl<S, P, Delay> Write<$Word> for Rs485<S, P, Delay>
+            where
+                S: Write<$Word>,
+                P: OutputPin,
+                Delay: DelayUs<u32>,
+            {
+                type Error = Rs485Error<S::Error, P::Error, Delay::Error>;
+
+                /// Writes the words, waiting for them to be sent before releasing the bus
+                fn try_bwrite_all(&mut self, buffer: &[$Word]) -> Result<(), Self::Error> {
+                    self.write(buffer)
+                }
+
+                /// Does nothing, as writes only return once the words have been sent
+                fn try_bflush(&mut self) -> Result<(), Self::Error> {
+                    Ok(())
+                }
+            }
+
+            impl<S, P, Delay> Read<$Word> for Rs485<S, P, Delay>
+            where
+                S: Read<$Word>,
+                P: OutputPin,
+                Delay: DelayUs<u32>,
+            {
+                type Error = Rs485Error<S::Error, P::Error, Delay::Error>;
+
+                fn try_read_exact(&mut self, buffer: &mut [$Word]) -> Result<(), Self::Error> {
+                    self.serial.try_read_exact(buffer).map_err(Rs485Error::Serial)
+                }
+            }
+        )*
+    };
+}
+
+impl_serial!(u8, u16);
@@ -0,0 +1,163 @@
+//! Mirroring of serial traffic
+//!
+//! A [`SerialTee`] wraps a serial interface and copies every word received from or written to it
+//! into a secondary sink implementing the blocking [`Write`] trait, e.g. a debug console, to
+//! snoop on the conversation with a modem or another device.
+//!
+//! Words are mirrored once they have been successfully read or written, in the order of the
+//! calls. Received and sent words aren't told apart, which suits command/response protocols.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::serial::tee::SerialTee;
+//! use embedded_hal::blocking::serial::{Read, Write};
+//! use embedded_hal::loopback;
+//!
+//! let modem = loopback::Serial::<u8, 16>::new();
+//! let console = loopback::Serial::<u8, 16>::new();
+//! let mut tee = SerialTee::new(modem, console);
+//!
+//! tee.try_bwrite_all(b"AT\r\n").unwrap();
+//! let mut echo = [0; 4];
+//! tee.try_read_exact(&mut echo).unwrap();
+//!
+//! let (_modem, mut console) = tee.destroy();
+//! let mut log = [0; 8];
+//! console.try_read_exact(&mut log).unwrap();
+//! assert_eq!(&log, b"AT\r\nAT\r\n");
+//! ```
+
+use super::{Read, Write};
+
+wrapper_error! {
+    /// Serial tee error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum TeeError<SerialError, SinkError> {
+        /// Underlying serial error
+        Serial(SerialError) => forward, "serial error",
+        /// Error of the sink
+        ///
+        /// The words have been read from or written to the serial interface nonetheless.
+        Sink(SinkError) => Other, "sink error",
+    }
+
+    impl crate::serial::Error => crate::serial::ErrorKind
+    where
+        SerialError: crate::serial::Error
+}
+
+/// Serial interface mirroring its traffic to a sink
+///
+/// The non-blocking serial traits are implemented for any word type the sink accepts, and the
+/// blocking ones for 8 and 16-bit words.
+pub struct SerialTee<S, W> {
+    serial: S,
+    sink: W,
+}
+
+impl<S, W> SerialTee<S, W> {
+    /// Creates a new tee mirroring the traffic of `serial` to `sink`
+    pub fn new(serial: S, sink: W) -> Self {
+        Self { serial, sink }
+    }
+
+    /// Returns a reference to the serial interface
+    pub fn serial(&self) -> &S {
+        &self.serial
+    }
+
+    /// Destroys the tee, returning the serial interface and the sink
+    pub fn destroy(self) -> (S, W) {
+        (self.serial, self.sink)
+    }
+}
+
+impl<S, W, Word> crate::serial::Read<Word> for SerialTee<S, W>
+where
+    S: crate::serial::Read<Word>,
+    W: Write<Word>,
+    Word: Copy,
+{
+    type Error = TeeError<S::Error, W::Error>;
+
+    fn try_read(&mut self) -> nb::Result<Word, Self::Error> {
+        let word = self
+            .serial
+            .try_read()
+            .map_err(|e| e.map(TeeError::Serial))?;
+        self.sink
+            .try_bwrite_all(&[word])
+            .map_err(|e| nb::Error::Other(TeeError::Sink(e)))?;
+
+        Ok(word)
+    }
+}
+
+impl<S, W, Word> crate::serial::Write<Word> for SerialTee<S, W>
+where
+    S: crate::serial::Write<Word>,
+    W: Write<Word>,
+    Word: Copy,
+{
+    type Error = TeeError<S::Error, W::Error>;
+
+    fn try_write(&mut self, word: Word) -> nb::Result<(), Self::Error> {
+        self.serial
+            .try_write(word)
+            .map_err(|e| e.map(TeeError::Serial))?;
+        self.sink
+            .try_bwrite_all(&[word])
+            .map_err(|e| nb::Error::Other(TeeError::Sink(e)))
+    }
+
+    /// Flushes the serial interface, then the sink
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        self.serial
+            .try_flush()
+            .map_err(|e| e.map(TeeError::Serial))?;
+        self.sink
+            .try_bflush()
+            .map_err(|e| nb::Error::Other(TeeError::Sink(e)))
+    }
+}
+
+macro_rules! impl_serial {
+    ($($Word:ty),*) => {
+        $(
+            impl<S, W> Write<$Word> for SerialTee<S, W>
+            where
+                S: Write<$Word>,
+                W: Write<$Word>,
+            {
+                type Error = TeeError<S::Error, W::Error>;
+
+                fn try_bwrite_all(&mut self, buffer: &[$Word]) -> Result<(), Self::Error> {
+                    self.serial.try_bwrite_all(buffer).map_err(TeeError::Serial)?;
+                    self.sink.try_bwrite_all(buffer).map_err(TeeError::Sink)
+                }
+
+                /// Flushes the serial interface, then the sink
+                fn try_bflush(&mut self) -> Result<(), Self::Error> {
+                    self.serial.try_bflush().map_err(TeeError::Serial)?;
+                    self.sink.try_bflush().map_err(TeeError::Sink)
+                }
+            }
+
+            impl<S, W> Read<$Word> for SerialTee<S, W>
+            where
+                S: Read<$Word>,
+                W: Write<$Word>,
+            {
+                type Error = TeeError<S::Error, W::Error>;
+
+                fn try_read_exact(&mut self, buffer: &mut [$Word]) -> Result<(), Self::Error> {
+                    self.serial.try_read_exact(buffer).map_err(TeeError::Serial)?;
+                    self.sink.try_bwrite_all(buffer).map_err(TeeError::Sink)
+                }
+            }
+        )*
+    };
+}
+
+impl_serial!(u8, u16);
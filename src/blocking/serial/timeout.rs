@@ -0,0 +1,145 @@
+//! Time bounded serial reads
+//!
+//! [`SerialTimeout`] implements the blocking [`Read`] trait over a non-blocking
+//! [`serial::Read`](crate::serial::Read), failing with [`SerialTimeoutError::Timeout`] if the
+//! words don't arrive within a bound, rather than waiting forever for a device that may never
+//! answer. The timeout covers a whole call, and is measured with a [`CountDown`].
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::serial::timeout::{SerialTimeout, SerialTimeoutError};
+//! use embedded_hal::blocking::serial::Read;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::timer::CountDown;
+//! # struct Uart;
+//! # impl embedded_hal::serial::Read<u8> for Uart {
+//! #     type Error = Infallible;
+//! #     fn try_read(&mut self) -> nb::Result<u8, Self::Error> { Err(nb::Error::WouldBlock) }
+//! # }
+//! # #[derive(Clone)]
+//! # struct Millis(u32);
+//! # struct Timer;
+//! # impl CountDown for Timer {
+//! #     type Error = Infallible;
+//! #     type Time = Millis;
+//! #     fn try_start<T>(&mut self, _: T) -> Result<(), Self::Error> where T: Into<Millis> { Ok(()) }
+//! #     fn try_wait(&mut self) -> nb::Result<(), Infallible> { Ok(()) }
+//! # }
+//! # let (uart, timer) = (Uart, Timer);
+//!
+//! let mut modem = SerialTimeout::new(uart, timer, Millis(500));
+//!
+//! let mut response = [0; 4];
+//! match modem.try_read_exact(&mut response) {
+//!     Ok(()) => { /* parse the response */ }
+//!     Err(SerialTimeoutError::Timeout) => { /* the modem didn't answer within 500 ms */ }
+//!     Err(e) => panic!("{:?}", e),
+//! }
+//! ```
+
+use super::Read;
+use crate::timer::CountDown;
+
+wrapper_error! {
+    /// Time bounded serial error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum SerialTimeoutError<SerialError, TimerError> {
+        /// Underlying serial error
+        Serial(SerialError) => forward, "serial error",
+        /// Underlying timer error
+        Timer(TimerError) => Other, "timer error",
+        /// The words were not received within the timeout
+        Timeout => Other, "serial read timed out",
+    }
+
+    impl crate::serial::Error => crate::serial::ErrorKind
+    where
+        SerialError: crate::serial::Error
+}
+
+/// Wraps a non-blocking serial interface, bounding the duration of blocking reads
+pub struct SerialTimeout<S, Timer: CountDown> {
+    serial: S,
+    timer: Timer,
+    timeout: Timer::Time,
+}
+
+impl<S, Timer> SerialTimeout<S, Timer>
+where
+    Timer: CountDown,
+    Timer::Time: Clone,
+{
+    /// Creates a new wrapper failing reads taking longer than `timeout`
+    pub fn new<T>(serial: S, timer: Timer, timeout: T) -> Self
+    where
+        T: Into<Timer::Time>,
+    {
+        Self {
+            serial,
+            timer,
+            timeout: timeout.into(),
+        }
+    }
+
+    /// Returns a mutable reference to the serial interface, e.g. to write to it
+    pub fn serial(&mut self) -> &mut S {
+        &mut self.serial
+    }
+
+    /// Destroys the wrapper, returning the serial interface and timer
+    pub fn destroy(self) -> (S, Timer) {
+        (self.serial, self.timer)
+    }
+
+    fn read_exact<Word>(
+        &mut self,
+        buffer: &mut [Word],
+    ) -> Result<(), SerialTimeoutError<S::Error, Timer::Error>>
+    where
+        S: crate::serial::Read<Word>,
+    {
+        self.timer
+            .try_start(self.timeout.clone())
+            .map_err(SerialTimeoutError::Timer)?;
+
+        for word in buffer {
+            *word = loop {
+                match self.serial.try_read() {
+                    Ok(word) => break word,
+                    Err(nb::Error::Other(e)) => return Err(SerialTimeoutError::Serial(e)),
+                    Err(nb::Error::WouldBlock) => match self.timer.try_wait() {
+                        Ok(()) => return Err(SerialTimeoutError::Timeout),
+                        Err(nb::Error::WouldBlock) => {}
+                        Err(nb::Error::Other(e)) => return Err(SerialTimeoutError::Timer(e)),
+                    },
+                }
+            };
+        }
+
+        Ok(())
+    }
+}
+
+// implemented for each word type, as a generic implementation would conflict with the
+// `read::Default` blanket implementation
+macro_rules! impl_read {
+    ($($Word:ty),*) => {
+        $(
+            impl<S, Timer> Read<$Word> for SerialTimeout<S, Timer>
+            where
+                S: crate::serial::Read<$Word>,
+                Timer: CountDown,
+                Timer::Time: Clone,
+            {
+                type Error = SerialTimeoutError<S::Error, Timer::Error>;
+
+                fn try_read_exact(&mut self, buffer: &mut [$Word]) -> Result<(), Self::Error> {
+                    self.read_exact(buffer)
+                }
+            }
+        )*
+    };
+}
+
+impl_read!(u8, u16);
@@ -0,0 +1,352 @@
+//! Blocking SMBus API
+//!
+//! The System Management Bus is a stricter subset of I2C used by battery gauges, power
+//! management ICs and PC hardware monitors. Its transfers follow a fixed set of protocols, each
+//! covered by a trait in this module, and can optionally be protected by a Packet Error Code
+//! (PEC), a CRC-8 over all the bytes of the transfer.
+//!
+//! [`Smbus`] implements all the traits on top of the blocking I2C traits, computing and checking
+//! the PEC if enabled. SMBus devices only use 7-bit addresses.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::smbus::{Smbus, WordData};
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::i2c::{Write, WriteRead};
+//! # struct I2c;
+//! # impl Write for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write(&mut self, _: u8, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl WriteRead for I2c {
+//! #     type Error = Infallible;
+//! #     fn try_write_read(&mut self, _: u8, _: &[u8], buffer: &mut [u8]) -> Result<(), Self::Error> {
+//! #         // voltage, then the PEC computed over the address and command bytes as well
+//! #         buffer.copy_from_slice(&[0x68, 0x10, 0x46]);
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let i2c = I2c;
+//!
+//! const GAUGE: u8 = 0x0B;
+//! const VOLTAGE: u8 = 0x09;
+//!
+//! let mut smbus = Smbus::with_pec(i2c);
+//! let millivolts = smbus.try_read_word(GAUGE, VOLTAGE).unwrap();
+//! assert_eq!(millivolts, 4200);
+//! ```
+
+use super::i2c::{Read, Write, WriteRead};
+
+/// Maximum number of data bytes of a block transfer
+pub const BLOCK_MAX: usize = 32;
+
+/// Quick command
+pub trait QuickCommand {
+    /// Error type
+    type Error;
+
+    /// Sends the address of the slave with the R/W bit set to `read`, without any data
+    fn try_quick_command(&mut self, address: u8, read: bool) -> Result<(), Self::Error>;
+}
+
+/// Send and receive byte
+pub trait Byte {
+    /// Error type
+    type Error;
+
+    /// Sends a single byte to the slave
+    fn try_send_byte(&mut self, address: u8, byte: u8) -> Result<(), Self::Error>;
+
+    /// Receives a single byte from the slave
+    fn try_receive_byte(&mut self, address: u8) -> Result<u8, Self::Error>;
+}
+
+/// Write and read byte
+pub trait ByteData {
+    /// Error type
+    type Error;
+
+    /// Writes `byte` to the register `command` of the slave
+    fn try_write_byte(&mut self, address: u8, command: u8, byte: u8) -> Result<(), Self::Error>;
+
+    /// Reads the byte register `command` of the slave
+    fn try_read_byte(&mut self, address: u8, command: u8) -> Result<u8, Self::Error>;
+}
+
+/// Write and read word
+pub trait WordData {
+    /// Error type
+    type Error;
+
+    /// Writes `word` to the register `command` of the slave, low byte first
+    fn try_write_word(&mut self, address: u8, command: u8, word: u16) -> Result<(), Self::Error>;
+
+    /// Reads the word register `command` of the slave, low byte first
+    fn try_read_word(&mut self, address: u8, command: u8) -> Result<u16, Self::Error>;
+}
+
+/// Block write and read
+pub trait Block {
+    /// Error type
+    type Error;
+
+    /// Writes `data`, preceded by its length, to the register `command` of the slave
+    ///
+    /// `data` can't be longer than [`BLOCK_MAX`] bytes.
+    fn try_block_write(&mut self, address: u8, command: u8, data: &[u8])
+        -> Result<(), Self::Error>;
+
+    /// Reads a block from the register `command` of the slave into `buffer`, returning its length
+    ///
+    /// At most [`BLOCK_MAX`] bytes are read. A block longer than `buffer` is an error.
+    fn try_block_read(
+        &mut self,
+        address: u8,
+        command: u8,
+        buffer: &mut [u8],
+    ) -> Result<usize, Self::Error>;
+}
+
+/// Computes the Packet Error Code of `bytes`
+///
+/// This is the CRC-8 with polynomial `x^8 + x^2 + x + 1` and a zero initial value. The bytes
+/// cover the whole transfer, starting with the address byte including the R/W bit.
+pub fn pec(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |crc, byte| {
+        (0..8).fold(crc ^ byte, |crc, _| {
+            if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
+
+wrapper_error! {
+    /// SMBus error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum Error<E> {
+        /// Underlying I2C error
+        I2c(E) => forward, "I2C error",
+        /// The received Packet Error Code doesn't match the data
+        Pec => Other, "packet error code mismatch",
+        /// The block is longer than [`BLOCK_MAX`] or the provided buffer, or its length changed
+        /// while it was read
+        BlockLength => Other, "invalid block length",
+    }
+
+    impl super::i2c::Error => super::i2c::ErrorKind where E: super::i2c::Error
+}
+
+/// SMBus implementation over the blocking I2C traits
+///
+/// Block reads receive the byte count in a first transfer and the block in a second one, as the
+/// I2C traits can't adjust the length of a read to the received data. The register must return
+/// the same block when read twice.
+pub struct Smbus<I2C> {
+    i2c: I2C,
+    pec: bool,
+}
+
+impl<I2C> Smbus<I2C> {
+    /// Creates a new SMBus without Packet Error Codes
+    pub fn new(i2c: I2C) -> Self {
+        Self { i2c, pec: false }
+    }
+
+    /// Creates a new SMBus appending and checking Packet Error Codes
+    pub fn with_pec(i2c: I2C) -> Self {
+        Self { i2c, pec: true }
+    }
+
+    /// Destroys the SMBus, returning the I2C bus
+    pub fn destroy(self) -> I2C {
+        self.i2c
+    }
+
+    /// Writes `bytes` to the slave, appending the PEC if enabled
+    fn write<E>(&mut self, address: u8, bytes: &[u8]) -> Result<(), Error<E>>
+    where
+        I2C: Write<Error = E>,
+    {
+        if !self.pec {
+            return self.i2c.try_write(address, bytes).map_err(Error::I2c);
+        }
+
+        let mut buf = [0; BLOCK_MAX + 4];
+        buf[0] = address << 1;
+        buf[1..=bytes.len()].copy_from_slice(bytes);
+        buf[bytes.len() + 1] = pec(&buf[..=bytes.len()]);
+
+        self.i2c
+            .try_write(address, &buf[1..bytes.len() + 2])
+            .map_err(Error::I2c)
+    }
+
+    /// Writes `command` to the slave and reads `N` bytes back, checking the PEC if enabled
+    fn write_read<E, const N: usize>(
+        &mut self,
+        address: u8,
+        command: u8,
+    ) -> Result<[u8; N], Error<E>>
+    where
+        I2C: WriteRead<Error = E>,
+    {
+        let mut buf = [0; BLOCK_MAX + 5];
+        let len = if self.pec { N + 1 } else { N };
+        self.i2c
+            .try_write_read(address, &[command], &mut buf[3..3 + len])
+            .map_err(Error::I2c)?;
+
+        if self.pec {
+            buf[..3].copy_from_slice(&[address << 1, command, address << 1 | 1]);
+            if pec(&buf[..3 + N]) != buf[3 + N] {
+                return Err(Error::Pec);
+            }
+        }
+
+        let mut data = [0; N];
+        data.copy_from_slice(&buf[3..3 + N]);
+
+        Ok(data)
+    }
+}
+
+impl<I2C, E> QuickCommand for Smbus<I2C>
+where
+    I2C: Write<Error = E> + Read<Error = E>,
+{
+    type Error = Error<E>;
+
+    fn try_quick_command(&mut self, address: u8, read: bool) -> Result<(), Self::Error> {
+        if read {
+            self.i2c.try_read(address, &mut []).map_err(Error::I2c)
+        } else {
+            self.i2c.try_write(address, &[]).map_err(Error::I2c)
+        }
+    }
+}
+
+impl<I2C, E> Byte for Smbus<I2C>
+where
+    I2C: Write<Error = E> + Read<Error = E>,
+{
+    type Error = Error<E>;
+
+    fn try_send_byte(&mut self, address: u8, byte: u8) -> Result<(), Self::Error> {
+        self.write(address, &[byte])
+    }
+
+    fn try_receive_byte(&mut self, address: u8) -> Result<u8, Self::Error> {
+        let mut buf = [0; 2];
+        let len = if self.pec { 2 } else { 1 };
+        self.i2c
+            .try_read(address, &mut buf[..len])
+            .map_err(Error::I2c)?;
+
+        if self.pec && pec(&[address << 1 | 1, buf[0]]) != buf[1] {
+            return Err(Error::Pec);
+        }
+
+        Ok(buf[0])
+    }
+}
+
+impl<I2C, E> ByteData for Smbus<I2C>
+where
+    I2C: Write<Error = E> + WriteRead<Error = E>,
+{
+    type Error = Error<E>;
+
+    fn try_write_byte(&mut self, address: u8, command: u8, byte: u8) -> Result<(), Self::Error> {
+        self.write(address, &[command, byte])
+    }
+
+    fn try_read_byte(&mut self, address: u8, command: u8) -> Result<u8, Self::Error> {
+        self.write_read::<E, 1>(address, command).map(|[b]| b)
+    }
+}
+
+impl<I2C, E> WordData for Smbus<I2C>
+where
+    I2C: Write<Error = E> + WriteRead<Error = E>,
+{
+    type Error = Error<E>;
+
+    fn try_write_word(&mut self, address: u8, command: u8, word: u16) -> Result<(), Self::Error> {
+        let [low, high] = word.to_le_bytes();
+        self.write(address, &[command, low, high])
+    }
+
+    fn try_read_word(&mut self, address: u8, command: u8) -> Result<u16, Self::Error> {
+        self.write_read::<E, 2>(address, command)
+            .map(u16::from_le_bytes)
+    }
+}
+
+impl<I2C, E> Block for Smbus<I2C>
+where
+    I2C: Write<Error = E> + WriteRead<Error = E>,
+{
+    type Error = Error<E>;
+
+    fn try_block_write(
+        &mut self,
+        address: u8,
+        command: u8,
+        data: &[u8],
+    ) -> Result<(), Self::Error> {
+        if data.len() > BLOCK_MAX {
+            return Err(Error::BlockLength);
+        }
+
+        let mut buf = [0; BLOCK_MAX + 2];
+        buf[0] = command;
+        buf[1] = data.len() as u8;
+        buf[2..data.len() + 2].copy_from_slice(data);
+
+        self.write(address, &buf[..data.len() + 2])
+    }
+
+    fn try_block_read(
+        &mut self,
+        address: u8,
+        command: u8,
+        buffer: &mut [u8],
+    ) -> Result<usize, Self::Error> {
+        // read the count on its own, then the block again with its exact length
+        let mut count = [0];
+        self.i2c
+            .try_write_read(address, &[command], &mut count)
+            .map_err(Error::I2c)?;
+
+        let count = usize::from(count[0]);
+        if count > buffer.len().min(BLOCK_MAX) {
+            return Err(Error::BlockLength);
+        }
+
+        let mut buf = [0; BLOCK_MAX + 5];
+        let read = if self.pec { count + 2 } else { count + 1 };
+        self.i2c
+            .try_write_read(address, &[command], &mut buf[3..3 + read])
+            .map_err(Error::I2c)?;
+
+        if usize::from(buf[3]) != count {
+            return Err(Error::BlockLength);
+        }
+
+        if self.pec {
+            buf[..3].copy_from_slice(&[address << 1, command, address << 1 | 1]);
+            if pec(&buf[..4 + count]) != buf[4 + count] {
+                return Err(Error::Pec);
+            }
+        }
+
+        buffer[..count].copy_from_slice(&buf[4..4 + count]);
+
+        Ok(count)
+    }
+}
@@ -0,0 +1,109 @@
+//! Splitting of large SPI transfers
+//!
+//! Some SPI peripherals, typically those backed by DMA, are limited in the number of words they
+//! can move per operation. [`ChunkedSpi`] splits each write or transfer into chunks of at most
+//! `N` words, so drivers can send e.g. a whole framebuffer in one call.
+//!
+//! Each chunk is a separate call to the inner bus, so chip select has to be managed around the
+//! adapter, e.g. with [`SpiWithCs`](super::spi_with_cs::SpiWithCs), rather than by the inner
+//! bus.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::spi::chunked::ChunkedSpi;
+//! use embedded_hal::blocking::spi::Write;
+//! # use core::convert::Infallible;
+//! # struct DmaSpi;
+//! # impl Write<u8> for DmaSpi {
+//! #     type Error = Infallible;
+//! #     fn try_write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+//! #         assert!(words.len() <= 255);
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let spi = DmaSpi;
+//!
+//! // the DMA engine can move at most 255 words at once
+//! let mut spi: ChunkedSpi<_, 255> = ChunkedSpi::new(spi);
+//!
+//! let framebuffer = [0; 128 * 64 / 8];
+//! spi.try_write(&framebuffer).unwrap();
+//! ```
+
+use super::{Flush, Transfer, Write};
+
+/// Wraps a blocking SPI implementation, splitting operations into chunks of at most `N` words
+///
+/// The blocking SPI traits are implemented for 8, 16 and 32-bit words.
+///
+/// # Panics
+///
+/// Operations panic if `N` is zero.
+pub struct ChunkedSpi<Spi, const N: usize> {
+    spi: Spi,
+}
+
+impl<Spi, const N: usize> ChunkedSpi<Spi, N> {
+    /// Wraps `spi`
+    pub fn new(spi: Spi) -> Self {
+        Self { spi }
+    }
+
+    /// Destroys the adapter, returning the SPI bus
+    pub fn destroy(self) -> Spi {
+        self.spi
+    }
+}
+
+macro_rules! impl_chunked {
+    ($($W:ty),*) => {
+        $(
+            impl<Spi, const N: usize> Flush<$W> for ChunkedSpi<Spi, N>
+            where
+                Spi: Flush<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_flush(&mut self) -> Result<(), Self::Error> {
+                    self.spi.try_flush()
+                }
+            }
+
+            impl<Spi, const N: usize> Transfer<$W> for ChunkedSpi<Spi, N>
+            where
+                Spi: Transfer<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_transfer<'w>(
+                    &mut self,
+                    words: &'w mut [$W],
+                ) -> Result<&'w [$W], Self::Error> {
+                    for chunk in words.chunks_mut(N) {
+                        self.spi.try_transfer(chunk)?;
+                    }
+
+                    Ok(words)
+                }
+            }
+
+            impl<Spi, const N: usize> Write<$W> for ChunkedSpi<Spi, N>
+            where
+                Spi: Write<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_write(&mut self, words: &[$W]) -> Result<(), Self::Error> {
+                    for chunk in words.chunks(N) {
+                        self.spi.try_write(chunk)?;
+                    }
+
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_chunked!(u8, u16, u32);
@@ -0,0 +1,117 @@
+//! Daisy-chained SPI devices
+//!
+//! Devices such as the MAX7219 LED driver or 74HC595 shift registers can be chained by wiring
+//! the data output of each device to the data input of the next one, with a common clock and
+//! chip select. Data is shifted through the whole chain while chip select is asserted and every
+//! device latches the frame it currently holds when it is released.
+//!
+//! [`DaisyChain`] sends one frame per device within a single chip select assertion. Devices are
+//! indexed from the one wired to the controller, index 0, to the far end of the chain, index
+//! `N - 1`.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::spi::daisy_chain::DaisyChain;
+//! use embedded_hal::blocking::spi::spi_with_cs::SpiWithCs;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::spi::WriteIter;
+//! # use embedded_hal::digital::OutputPin;
+//! # struct Spi;
+//! # impl embedded_hal::blocking::spi::Flush for Spi {
+//! #     type Error = Infallible;
+//! #     fn try_flush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl WriteIter<u8> for Spi {
+//! #     type Error = Infallible;
+//! #     fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+//! #     where
+//! #         WI: IntoIterator<Item = u8>,
+//! #     {
+//! #         assert_eq!(words.into_iter().count(), 8);
+//! #         Ok(())
+//! #     }
+//! # }
+//! # struct Pin;
+//! # impl OutputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (spi, cs) = (Spi, Pin);
+//!
+//! // four chained MAX7219s
+//! let mut chain: DaisyChain<_, 4> = DaisyChain::new(SpiWithCs::new(spi, cs));
+//!
+//! // set the intensity of all the devices
+//! chain.try_write_all(&[&[0x0A, 0x08]; 4]).unwrap();
+//!
+//! // light the first row of the third device, the others receive no-op frames
+//! chain.try_write_device(2, &[0x01, 0xFF], &[0x00, 0x00]).unwrap();
+//! ```
+
+use super::{ManagedCs, WriteIter};
+
+/// Writes frames to `N` daisy-chained devices
+///
+/// The inner bus has to implement [`ManagedCs`], as the devices latch their frames when chip select
+/// is released at the end of each call.
+pub struct DaisyChain<Spi, const N: usize> {
+    spi: Spi,
+}
+
+impl<Spi, const N: usize> DaisyChain<Spi, N>
+where
+    Spi: ManagedCs,
+{
+    /// Wraps `spi`, chaining `N` devices
+    pub fn new(spi: Spi) -> Self {
+        Self { spi }
+    }
+
+    /// Destroys the adapter, returning the SPI bus
+    pub fn destroy(self) -> Spi {
+        self.spi
+    }
+
+    /// Sends `frames[i]` to the device with index `i`
+    ///
+    /// The frames are sent in a single call to the inner bus, starting with the frame for the
+    /// device at the far end of the chain.
+    pub fn try_write_all<W>(&mut self, frames: &[&[W]; N]) -> Result<(), Spi::Error>
+    where
+        Spi: WriteIter<W>,
+        W: Copy,
+    {
+        self.spi
+            .try_write_iter(frames.iter().rev().flat_map(|f| f.iter().copied()))
+    }
+
+    /// Sends `frame` to the device with index `index` and `noop` to all the other devices
+    ///
+    /// `noop` must be a frame the devices ignore, for example a write to the no-op register of a
+    /// MAX7219, and should have the same length as `frame`. Devices without such a frame, like
+    /// shift registers, have to be written with [`try_write_all`](DaisyChain::try_write_all).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not lower than `N`.
+    pub fn try_write_device<W>(
+        &mut self,
+        index: usize,
+        frame: &[W],
+        noop: &[W],
+    ) -> Result<(), Spi::Error>
+    where
+        Spi: WriteIter<W>,
+        W: Copy,
+    {
+        assert!(index < N, "device index out of range");
+
+        self.spi.try_write_iter(
+            (0..N)
+                .rev()
+                .flat_map(|i| if i == index { frame } else { noop }.iter().copied()),
+        )
+    }
+}
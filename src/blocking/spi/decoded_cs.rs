@@ -0,0 +1,218 @@
+//! Chip select through an external address decoder
+//!
+//! Boards with many SPI devices often generate the chip select lines with a 3-to-8 line decoder
+//! such as the 74HC138: `N` address pins select one of `2^N` outputs, which is driven low while
+//! the decoder's enable input is asserted. [`SpiWithDecodedCs`] sets the address pins to the
+//! device's address before each operation and asserts the enable pin for its duration.
+//!
+//! The enable pin is used rather than changing the address at the start and end of each
+//! operation as the address pins can't be updated atomically, which could otherwise briefly
+//! select unrelated devices.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::spi::decoded_cs::SpiWithDecodedCs;
+//! use embedded_hal::blocking::spi::Write;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::digital::OutputPin;
+//! # struct Spi;
+//! # impl embedded_hal::blocking::spi::Flush for Spi {
+//! #     type Error = Infallible;
+//! #     fn try_flush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl Write<u8> for Spi {
+//! #     type Error = Infallible;
+//! #     fn try_write(&mut self, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # struct Pin;
+//! # impl OutputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (spi, a0, a1, a2, enable) = (Spi, Pin, Pin, Pin, Pin);
+//!
+//! // the DAC is attached to output Y5 of the decoder
+//! let mut spi = SpiWithDecodedCs::new(spi, [a0, a1, a2], enable, 5);
+//! spi.try_write(&[0x30, 0x00]).unwrap();
+//!
+//! // the ADC to output Y2
+//! spi.set_address(2);
+//! spi.try_write(&[0x01, 0x80, 0x00]).unwrap();
+//! ```
+
+use super::spi_with_cs::SpiWithCsError;
+use super::{Flush, ManagedCs, Operation, Transactional, Transfer, Write, WriteIter};
+use crate::digital::{OutputPin, PinState};
+
+/// Wraps a blocking SPI implementation with chip select generated by an address decoder
+///
+/// `address` pins are given least significant bit first and drive the decoder's address inputs,
+/// `enable` drives its active low enable input.
+///
+/// The blocking SPI traits are implemented for 8, 16 and 32-bit words.
+pub struct SpiWithDecodedCs<Spi, Pin, Enable, const N: usize> {
+    spi: Spi,
+    address_pins: [Pin; N],
+    enable: Enable,
+    address: usize,
+}
+
+impl<Spi, Pin, Enable, const N: usize> ManagedCs for SpiWithDecodedCs<Spi, Pin, Enable, N> {}
+
+impl<Spi, Pin, Enable, PinError, const N: usize> SpiWithDecodedCs<Spi, Pin, Enable, N>
+where
+    Pin: OutputPin<Error = PinError>,
+    Enable: OutputPin<Error = PinError>,
+{
+    /// Creates a new wrapper selecting the decoder output `address`
+    ///
+    /// The enable pin is expected to be released (high) already.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `address` can't be encoded with `N` address pins.
+    pub fn new(spi: Spi, address_pins: [Pin; N], enable: Enable, address: usize) -> Self {
+        check_address::<N>(address);
+
+        Self {
+            spi,
+            address_pins,
+            enable,
+            address,
+        }
+    }
+
+    /// Selects the decoder output used for subsequent operations
+    ///
+    /// # Panics
+    ///
+    /// Panics if `address` can't be encoded with `N` address pins.
+    pub fn set_address(&mut self, address: usize) {
+        check_address::<N>(address);
+
+        self.address = address;
+    }
+
+    /// Returns the currently selected decoder output
+    pub fn address(&self) -> usize {
+        self.address
+    }
+
+    /// Destroys the wrapper, returning the SPI bus, the address pins and the enable pin
+    pub fn destroy(self) -> (Spi, [Pin; N], Enable) {
+        (self.spi, self.address_pins, self.enable)
+    }
+
+    /// Runs `f` with the decoder output selected, releasing it afterwards even if `f` fails
+    ///
+    /// The bus is flushed before releasing the output, so that the last words have been shifted
+    /// out.
+    fn with_cs<W, R, SpiError, F>(&mut self, f: F) -> Result<R, SpiWithCsError<SpiError, PinError>>
+    where
+        Spi: Flush<W, Error = SpiError>,
+        F: FnOnce(&mut Spi) -> Result<R, SpiError>,
+    {
+        let address = self.address;
+        for (bit, pin) in self.address_pins.iter_mut().enumerate() {
+            pin.try_set_state(PinState::from(address & (1 << bit) != 0))
+                .map_err(SpiWithCsError::Pin)?;
+        }
+
+        self.enable.try_set_low().map_err(SpiWithCsError::Pin)?;
+
+        let res = f(&mut self.spi).map_err(SpiWithCsError::Spi);
+        let flushed = self.spi.try_flush().map_err(SpiWithCsError::Spi);
+
+        self.enable.try_set_high().map_err(SpiWithCsError::Pin)?;
+
+        let res = res?;
+        flushed?;
+
+        Ok(res)
+    }
+}
+
+/// Checks that `address` selects one of the `2^N` decoder outputs
+fn check_address<const N: usize>(address: usize) {
+    assert!(
+        address.checked_shr(N as u32).unwrap_or(0) == 0,
+        "address {} can't be encoded with {} address pins",
+        address,
+        N
+    );
+}
+
+macro_rules! impl_decoded_cs {
+    ($($W:ty),*) => {
+        $(
+            impl<Spi, Pin, Enable, PinError, E, const N: usize> Transfer<$W>
+                for SpiWithDecodedCs<Spi, Pin, Enable, N>
+            where
+                Spi: Transfer<$W, Error = E> + Flush<$W, Error = E>,
+                Pin: OutputPin<Error = PinError>,
+                Enable: OutputPin<Error = PinError>,
+            {
+                type Error = SpiWithCsError<E, PinError>;
+
+                fn try_transfer<'w>(
+                    &mut self,
+                    words: &'w mut [$W],
+                ) -> Result<&'w [$W], Self::Error> {
+                    self.with_cs(move |spi| spi.try_transfer(words))
+                }
+            }
+
+            impl<Spi, Pin, Enable, PinError, E, const N: usize> Write<$W>
+                for SpiWithDecodedCs<Spi, Pin, Enable, N>
+            where
+                Spi: Write<$W, Error = E> + Flush<$W, Error = E>,
+                Pin: OutputPin<Error = PinError>,
+                Enable: OutputPin<Error = PinError>,
+            {
+                type Error = SpiWithCsError<E, PinError>;
+
+                fn try_write(&mut self, words: &[$W]) -> Result<(), Self::Error> {
+                    self.with_cs(|spi| spi.try_write(words))
+                }
+            }
+
+            impl<Spi, Pin, Enable, PinError, E, const N: usize> WriteIter<$W>
+                for SpiWithDecodedCs<Spi, Pin, Enable, N>
+            where
+                Spi: WriteIter<$W, Error = E> + Flush<$W, Error = E>,
+                Pin: OutputPin<Error = PinError>,
+                Enable: OutputPin<Error = PinError>,
+            {
+                type Error = SpiWithCsError<E, PinError>;
+
+                fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+                where
+                    WI: IntoIterator<Item = $W>,
+                {
+                    self.with_cs(|spi| spi.try_write_iter(words))
+                }
+            }
+
+            impl<Spi, Pin, Enable, PinError, E, const N: usize> Transactional<$W>
+                for SpiWithDecodedCs<Spi, Pin, Enable, N>
+            where
+                Spi: Transactional<$W, Error = E> + Flush<$W, Error = E>,
+                Pin: OutputPin<Error = PinError>,
+                Enable: OutputPin<Error = PinError>,
+            {
+                type Error = SpiWithCsError<E, PinError>;
+
+                fn try_exec<'a>(
+                    &mut self,
+                    operations: &mut [Operation<'a, $W>],
+                ) -> Result<(), Self::Error> {
+                    self.with_cs(|spi| spi.try_exec(operations))
+                }
+            }
+        )*
+    };
+}
+
+impl_decoded_cs!(u8, u16, u32);
@@ -0,0 +1,91 @@
+//! Blocking SPI over a non-blocking `spi::FullDuplex` implementation
+//!
+//! HAL implementations provide the blocking SPI traits for their `spi::FullDuplex` buses by
+//! opting into the `Default` marker traits of this module's parent. For a bus that doesn't,
+//! [`BlockingFullDuplex`] provides the blocking traits on top of `spi::FullDuplex` directly.
+//!
+//! Combined with a chip select pin, through
+//! [`SpiWithCs::from_full_duplex`](super::spi_with_cs::SpiWithCs::from_full_duplex), this gives
+//! a blocking bus with managed chip select in one step.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::spi::spi_with_cs::SpiWithCs;
+//! use embedded_hal::blocking::spi::Transfer;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::digital::OutputPin;
+//! # use embedded_hal::spi::FullDuplex;
+//! # struct Spi(u8);
+//! # impl FullDuplex<u8> for Spi {
+//! #     type Error = Infallible;
+//! #     fn try_read(&mut self) -> nb::Result<u8, Self::Error> { Ok(self.0) }
+//! #     fn try_send(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+//! #         self.0 = word;
+//! #         Ok(())
+//! #     }
+//! # }
+//! # struct Pin;
+//! # impl OutputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (spi, cs) = (Spi(0), Pin);
+//!
+//! let mut flash = SpiWithCs::from_full_duplex(spi, cs);
+//! flash.try_transfer(&mut [0x9F, 0x00, 0x00, 0x00]).unwrap();
+//! ```
+
+use super::{flush, transactional, transfer, write, write_iter};
+use crate::spi::FullDuplex;
+
+/// Provides the blocking SPI traits for a `spi::FullDuplex` implementation
+///
+/// The blocking traits are implemented through their `Default` implementations, for all the word
+/// types the inner bus supports.
+pub struct BlockingFullDuplex<Spi> {
+    spi: Spi,
+}
+
+impl<Spi> BlockingFullDuplex<Spi> {
+    /// Wraps `spi`
+    pub fn new(spi: Spi) -> Self {
+        Self { spi }
+    }
+
+    /// Destroys the adapter, returning the SPI bus
+    pub fn destroy(self) -> Spi {
+        self.spi
+    }
+}
+
+impl<W, Spi> FullDuplex<W> for BlockingFullDuplex<Spi>
+where
+    Spi: FullDuplex<W>,
+{
+    type Error = Spi::Error;
+
+    fn try_read(&mut self) -> nb::Result<W, Self::Error> {
+        self.spi.try_read()
+    }
+
+    fn try_send(&mut self, word: W) -> nb::Result<(), Self::Error> {
+        self.spi.try_send(word)
+    }
+}
+
+impl<W, Spi: FullDuplex<W>> flush::Default<W> for BlockingFullDuplex<Spi> {}
+
+impl<W, Spi: FullDuplex<W>> transfer::Default<W> for BlockingFullDuplex<Spi> {}
+
+impl<W, Spi: FullDuplex<W>> write::Default<W> for BlockingFullDuplex<Spi> {}
+
+impl<W, Spi: FullDuplex<W>> write_iter::Default<W> for BlockingFullDuplex<Spi> {}
+
+impl<W, Spi> transactional::Default<W> for BlockingFullDuplex<Spi>
+where
+    Spi: FullDuplex<W>,
+    W: Clone,
+{
+}
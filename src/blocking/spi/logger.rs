@@ -0,0 +1,167 @@
+//! Tracing of SPI traffic
+//!
+//! [`SpiLogger`] wraps a blocking SPI implementation and reports every word sent to and received
+//! from the slave to a user provided callback, which makes it possible to debug a driver without
+//! a logic analyzer.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::spi::logger::SpiLogger;
+//! use embedded_hal::blocking::spi::Transfer;
+//! use embedded_hal::spi::Direction;
+//! # use core::convert::Infallible;
+//! # struct Spi;
+//! # impl Transfer<u8> for Spi {
+//! #     type Error = Infallible;
+//! #     fn try_transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+//! #         Ok(words)
+//! #     }
+//! # }
+//! # let spi = Spi;
+//!
+//! let mut spi = SpiLogger::new(spi, |direction: Direction, words: &[u8]| {
+//!     // e.g. forward to a debug console
+//!     println!("{:?} {} words: {:02x?}", direction, words.len(), words);
+//! });
+//!
+//! spi.try_transfer(&mut [0x9F, 0x00, 0x00, 0x00]).unwrap();
+//! ```
+
+use super::{Flush, ManagedCs, Operation, Transactional, Transfer, Write, WriteIter};
+use crate::spi::Direction;
+
+/// Wraps a blocking SPI implementation, passing all traffic to a callback
+///
+/// For each call the callback is first invoked with [`Direction::Transmit`] and the words about
+/// to be sent, then the call is forwarded to the inner bus. Words received from the slave are
+/// reported afterwards with [`Direction::Receive`]. Iterator writes are reported one word at a
+/// time.
+///
+/// The blocking SPI traits are implemented for 8, 16 and 32-bit words, with the callback taking a
+/// slice of the corresponding word type.
+pub struct SpiLogger<Spi, F> {
+    spi: Spi,
+    f: F,
+}
+
+impl<Spi, F> SpiLogger<Spi, F> {
+    /// Creates a new logger wrapping `spi` and reporting to `f`
+    pub fn new(spi: Spi, f: F) -> Self {
+        Self { spi, f }
+    }
+
+    /// Destroys the logger, returning the SPI bus and callback
+    pub fn destroy(self) -> (Spi, F) {
+        (self.spi, self.f)
+    }
+}
+
+impl<Spi: ManagedCs, F> ManagedCs for SpiLogger<Spi, F> {}
+
+macro_rules! impl_spi_logger {
+    ($($W:ty),*) => {
+        $(
+            impl<Spi, F> Flush<$W> for SpiLogger<Spi, F>
+            where
+                Spi: Flush<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_flush(&mut self) -> Result<(), Self::Error> {
+                    self.spi.try_flush()
+                }
+            }
+
+            impl<Spi, F> Transfer<$W> for SpiLogger<Spi, F>
+            where
+                Spi: Transfer<$W>,
+                F: FnMut(Direction, &[$W]),
+            {
+                type Error = Spi::Error;
+
+                fn try_transfer<'w>(
+                    &mut self,
+                    words: &'w mut [$W],
+                ) -> Result<&'w [$W], Self::Error> {
+                    (self.f)(Direction::Transmit, words);
+                    let words = self.spi.try_transfer(words)?;
+                    (self.f)(Direction::Receive, words);
+
+                    Ok(words)
+                }
+            }
+
+            impl<Spi, F> Write<$W> for SpiLogger<Spi, F>
+            where
+                Spi: Write<$W>,
+                F: FnMut(Direction, &[$W]),
+            {
+                type Error = Spi::Error;
+
+                fn try_write(&mut self, words: &[$W]) -> Result<(), Self::Error> {
+                    (self.f)(Direction::Transmit, words);
+                    self.spi.try_write(words)
+                }
+            }
+
+            impl<Spi, F> WriteIter<$W> for SpiLogger<Spi, F>
+            where
+                Spi: WriteIter<$W>,
+                F: FnMut(Direction, &[$W]),
+            {
+                type Error = Spi::Error;
+
+                fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+                where
+                    WI: IntoIterator<Item = $W>,
+                {
+                    let f = &mut self.f;
+                    self.spi.try_write_iter(
+                        words
+                            .into_iter()
+                            .inspect(|word| f(Direction::Transmit, core::slice::from_ref(word))),
+                    )
+                }
+            }
+
+            impl<Spi, F> Transactional<$W> for SpiLogger<Spi, F>
+            where
+                Spi: Transactional<$W>,
+                F: FnMut(Direction, &[$W]),
+            {
+                type Error = Spi::Error;
+
+                fn try_exec<'a>(
+                    &mut self,
+                    operations: &mut [Operation<'a, $W>],
+                ) -> Result<(), Self::Error> {
+                    for op in operations.iter() {
+                        match op {
+                            Operation::Read(_) | Operation::DelayUs(_) => {}
+                            Operation::Write(w) | Operation::Transfer(_, w) => {
+                                (self.f)(Direction::Transmit, w)
+                            }
+                            Operation::TransferInPlace(t) => (self.f)(Direction::Transmit, t),
+                        }
+                    }
+
+                    self.spi.try_exec(operations)?;
+
+                    for op in operations.iter() {
+                        match op {
+                            Operation::Write(_) | Operation::DelayUs(_) => {}
+                            Operation::Read(r)
+                            | Operation::Transfer(r, _)
+                            | Operation::TransferInPlace(r) => (self.f)(Direction::Receive, r),
+                        }
+                    }
+
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_spi_logger!(u8, u16, u32);
@@ -0,0 +1,211 @@
+//! Least significant bit first framing on MSB first buses
+//!
+//! Some devices shift words least significant bit first, which not all SPI peripherals can be
+//! configured for. [`LsbFirst`] reverses the bit order of every word sent and received, so that
+//! a MSB first bus produces the bit stream such devices expect.
+//!
+//! Written words are reversed into a buffer and forwarded to the inner bus in chunks of up to 32
+//! words, and the operations of a transaction are forwarded one at a time, so chip select has to
+//! be managed around the adapter, e.g. with [`SpiWithCs`](super::spi_with_cs::SpiWithCs), rather
+//! than by the inner bus.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::spi::lsb_first::LsbFirst;
+//! use embedded_hal::blocking::spi::Transfer;
+//! use embedded_hal::loopback;
+//!
+//! let mut spi = LsbFirst::new(loopback::Spi::with_transform(|word: u8| {
+//!     // the device sees the reversed bits
+//!     assert_eq!(word, 0b1000_0000);
+//!     word
+//! }));
+//!
+//! assert_eq!(spi.try_transfer(&mut [0b0000_0001]).unwrap(), &[0b0000_0001]);
+//! ```
+
+use super::{Flush, Operation, Transactional, Transfer, Write, WriteIter};
+
+/// Number of words forwarded to the inner bus per call
+const CHUNK_WORDS: usize = 32;
+
+/// Wraps a blocking SPI implementation, reversing the bit order of all words
+///
+/// The blocking SPI traits are implemented for 8, 16 and 32-bit words.
+pub struct LsbFirst<Spi> {
+    spi: Spi,
+}
+
+impl<Spi> LsbFirst<Spi> {
+    /// Wraps `spi`
+    pub fn new(spi: Spi) -> Self {
+        Self { spi }
+    }
+
+    /// Destroys the adapter, returning the SPI bus
+    pub fn destroy(self) -> Spi {
+        self.spi
+    }
+
+    /// Executes write operations for the reversed `words`, in chunks
+    fn exec_write<W>(&mut self, words: &[W]) -> Result<(), Spi::Error>
+    where
+        Spi: Transactional<W>,
+        W: ReverseBits + Default + 'static,
+    {
+        for chunk in words.chunks(CHUNK_WORDS) {
+            let mut buf = [W::default(); CHUNK_WORDS];
+            let buf = &mut buf[..chunk.len()];
+            buf.copy_from_slice(chunk);
+            reverse(buf);
+
+            self.spi.try_exec(&mut [Operation::Write(buf)])?;
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! impl_lsb_first {
+    ($($W:ty),*) => {
+        $(
+            impl<Spi> Flush<$W> for LsbFirst<Spi>
+            where
+                Spi: Flush<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_flush(&mut self) -> Result<(), Self::Error> {
+                    self.spi.try_flush()
+                }
+            }
+
+            impl<Spi> Transfer<$W> for LsbFirst<Spi>
+            where
+                Spi: Transfer<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_transfer<'w>(
+                    &mut self,
+                    words: &'w mut [$W],
+                ) -> Result<&'w [$W], Self::Error> {
+                    reverse(words);
+                    self.spi.try_transfer(words)?;
+                    reverse(words);
+
+                    Ok(words)
+                }
+            }
+
+            impl<Spi> Write<$W> for LsbFirst<Spi>
+            where
+                Spi: Write<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_write(&mut self, words: &[$W]) -> Result<(), Self::Error> {
+                    for chunk in words.chunks(CHUNK_WORDS) {
+                        let mut buf = [0; CHUNK_WORDS];
+                        let buf = &mut buf[..chunk.len()];
+                        buf.copy_from_slice(chunk);
+                        reverse(buf);
+
+                        self.spi.try_write(buf)?;
+                    }
+
+                    Ok(())
+                }
+            }
+
+            impl<Spi> WriteIter<$W> for LsbFirst<Spi>
+            where
+                Spi: WriteIter<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+                where
+                    WI: IntoIterator<Item = $W>,
+                {
+                    self.spi
+                        .try_write_iter(words.into_iter().map(<$W>::reverse_bits))
+                }
+            }
+
+            impl<Spi> Transactional<$W> for LsbFirst<Spi>
+            where
+                Spi: Transactional<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_exec<'a>(
+                    &mut self,
+                    operations: &mut [Operation<'a, $W>],
+                ) -> Result<(), Self::Error> {
+                    for op in operations.iter_mut() {
+                        match op {
+                            Operation::Read(r) => {
+                                self.spi.try_exec(&mut [Operation::Read(r)])?;
+                                reverse(r);
+                            }
+                            Operation::Write(w) => self.exec_write(w)?,
+                            Operation::Transfer(r, w) => {
+                                let n = r.len().min(w.len());
+                                let (head, tail) = r.split_at_mut(n);
+                                head.copy_from_slice(&w[..n]);
+                                reverse(head);
+
+                                self.spi.try_exec(&mut [
+                                    Operation::TransferInPlace(head),
+                                    Operation::Read(tail),
+                                ])?;
+                                reverse(r);
+
+                                self.exec_write(&w[n..])?;
+                            }
+                            Operation::TransferInPlace(t) => {
+                                reverse(t);
+                                self.spi.try_exec(&mut [Operation::TransferInPlace(t)])?;
+                                reverse(t);
+                            }
+                            Operation::DelayUs(us) => {
+                                self.spi.try_exec(&mut [Operation::DelayUs(*us)])?;
+                            }
+                        }
+                    }
+
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_lsb_first!(u8, u16, u32);
+
+/// Reverses the bit order of each word in `words`
+fn reverse<W: ReverseBits>(words: &mut [W]) {
+    for word in words {
+        *word = word.reverse();
+    }
+}
+
+trait ReverseBits: Copy {
+    fn reverse(self) -> Self;
+}
+
+macro_rules! impl_reverse_bits {
+    ($($W:ty),*) => {
+        $(
+            impl ReverseBits for $W {
+                fn reverse(self) -> Self {
+                    self.reverse_bits()
+                }
+            }
+        )*
+    };
+}
+
+impl_reverse_bits!(u8, u16, u32);
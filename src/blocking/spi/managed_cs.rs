@@ -0,0 +1,169 @@
+//! Adapters to require or assert the [`ManagedCs`] contract
+//!
+//! Drivers that rely on chip select being managed by the bus can store a [`RequireManagedCs`]
+//! rather than a bare bus type, which can only be constructed from an implementer of
+//! [`ManagedCs`]. Buses where chip select is already handled elsewhere, e.g. by the SPI
+//! peripheral itself, can be marked as such with [`AssumeManagedCs`].
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::spi::managed_cs::{AssumeManagedCs, RequireManagedCs};
+//! use embedded_hal::blocking::spi::{ManagedCs, Write};
+//! # use core::convert::Infallible;
+//!
+//! pub struct Driver<Spi> {
+//!     spi: RequireManagedCs<Spi>,
+//! }
+//!
+//! impl<Spi> Driver<Spi>
+//! where
+//!     Spi: Write<u8> + ManagedCs,
+//! {
+//!     pub fn new(spi: Spi) -> Self {
+//!         Driver {
+//!             spi: RequireManagedCs::new(spi),
+//!         }
+//!     }
+//!
+//!     pub fn reset(&mut self) -> Result<(), Spi::Error> {
+//!         self.spi.try_write(&[0x66, 0x99])
+//!     }
+//! }
+//!
+//! # struct HardwareCsSpi;
+//! # impl Write<u8> for HardwareCsSpi {
+//! #     type Error = Infallible;
+//! #     fn try_write(&mut self, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! // SPI peripheral driving the chip select line in hardware
+//! let mut driver = Driver::new(AssumeManagedCs::new(HardwareCsSpi));
+//! driver.reset().unwrap();
+//! ```
+
+use super::{Flush, ManagedCs, Operation, Transactional, Transfer, Write, WriteIter};
+
+/// Wraps a blocking SPI implementation that is statically known to implement [`ManagedCs`]
+///
+/// The blocking SPI traits are implemented for 8, 16 and 32-bit words.
+pub struct RequireManagedCs<Spi> {
+    spi: Spi,
+}
+
+impl<Spi> RequireManagedCs<Spi>
+where
+    Spi: ManagedCs,
+{
+    /// Wraps `spi`
+    pub fn new(spi: Spi) -> Self {
+        Self { spi }
+    }
+
+    /// Destroys the wrapper, returning the SPI bus
+    pub fn destroy(self) -> Spi {
+        self.spi
+    }
+}
+
+impl<Spi: ManagedCs> ManagedCs for RequireManagedCs<Spi> {}
+
+/// Marks a blocking SPI implementation as implementing [`ManagedCs`]
+///
+/// This is an escape hatch for buses where chip select is asserted by other means, e.g. by the
+/// hardware of the SPI peripheral or because a single device is attached with its chip select
+/// tied low. It is the task of the user of this wrapper to ensure the contract holds.
+///
+/// The blocking SPI traits are implemented for 8, 16 and 32-bit words.
+pub struct AssumeManagedCs<Spi> {
+    spi: Spi,
+}
+
+impl<Spi> AssumeManagedCs<Spi> {
+    /// Wraps `spi`, asserting that it manages chip select
+    pub fn new(spi: Spi) -> Self {
+        Self { spi }
+    }
+
+    /// Destroys the wrapper, returning the SPI bus
+    pub fn destroy(self) -> Spi {
+        self.spi
+    }
+}
+
+impl<Spi> ManagedCs for AssumeManagedCs<Spi> {}
+
+macro_rules! impl_forward {
+    ($wrapper:ident $bounds:tt; $($W:ty),*) => {
+        $(
+            impl_forward!(@word $wrapper $bounds $W);
+        )*
+    };
+    (@word $wrapper:ident [$($bound:tt)*] $W:ty) => {
+        impl<Spi> Flush<$W> for $wrapper<Spi>
+        where
+            Spi: Flush<$W> $($bound)*,
+        {
+            type Error = Spi::Error;
+
+            fn try_flush(&mut self) -> Result<(), Self::Error> {
+                self.spi.try_flush()
+            }
+        }
+
+        impl<Spi> Transfer<$W> for $wrapper<Spi>
+        where
+            Spi: Transfer<$W> $($bound)*,
+        {
+            type Error = Spi::Error;
+
+            fn try_transfer<'w>(
+                &mut self,
+                words: &'w mut [$W],
+            ) -> Result<&'w [$W], Self::Error> {
+                self.spi.try_transfer(words)
+            }
+        }
+
+        impl<Spi> Write<$W> for $wrapper<Spi>
+        where
+            Spi: Write<$W> $($bound)*,
+        {
+            type Error = Spi::Error;
+
+            fn try_write(&mut self, words: &[$W]) -> Result<(), Self::Error> {
+                self.spi.try_write(words)
+            }
+        }
+
+        impl<Spi> WriteIter<$W> for $wrapper<Spi>
+        where
+            Spi: WriteIter<$W> $($bound)*,
+        {
+            type Error = Spi::Error;
+
+            fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+            where
+                WI: IntoIterator<Item = $W>,
+            {
+                self.spi.try_write_iter(words)
+            }
+        }
+
+        impl<Spi> Transactional<$W> for $wrapper<Spi>
+        where
+            Spi: Transactional<$W> $($bound)*,
+        {
+            type Error = Spi::Error;
+
+            fn try_exec<'a>(
+                &mut self,
+                operations: &mut [Operation<'a, $W>],
+            ) -> Result<(), Self::Error> {
+                self.spi.try_exec(operations)
+            }
+        }
+    };
+}
+
+impl_forward!(RequireManagedCs [+ ManagedCs]; u8, u16, u32);
+impl_forward!(AssumeManagedCs []; u8, u16, u32);
@@ -0,0 +1,332 @@
+//! Blocking SPI API
+
+pub mod chunked;
+pub mod daisy_chain;
+pub mod decoded_cs;
+pub mod full_duplex;
+pub mod logger;
+pub mod lsb_first;
+pub mod managed_cs;
+pub mod packed;
+pub mod retry;
+pub mod shared;
+pub mod spi_with_cs;
+
+/// Blocking transfer
+pub trait Transfer<W> {
+    /// Error type
+    type Error;
+
+    /// Sends `words` to the slave. Returns the `words` received from the slave
+    fn try_transfer<'w>(&mut self, words: &'w mut [W]) -> Result<&'w [W], Self::Error>;
+
+    /// Sends the `N` words of `words` to the slave, replacing them with the words received
+    ///
+    /// This is meant for register sized transfers, where the length is known at compile time.
+    /// Implementations can override it to take advantage of the fixed length, the default
+    /// implementation calls `try_transfer`, which is expected to receive into `words`.
+    ///
+    /// ```
+    /// use embedded_hal::blocking::spi::Transfer;
+    /// # let mut spi = embedded_hal::loopback::Spi::new();
+    ///
+    /// let mut id = [0x9Fu8, 0, 0, 0];
+    /// spi.try_transfer_exact(&mut id).unwrap();
+    /// ```
+    fn try_transfer_exact<const N: usize>(&mut self, words: &mut [W; N]) -> Result<(), Self::Error>
+    where
+        Self: Sized,
+    {
+        self.try_transfer(words).map(|_| ())
+    }
+}
+
+/// Blocking write
+pub trait Write<W> {
+    /// Error type
+    type Error;
+
+    /// Sends `words` to the slave, ignoring all the incoming words
+    fn try_write(&mut self, words: &[W]) -> Result<(), Self::Error>;
+}
+
+/// Blocking write (iterator version)
+pub trait WriteIter<W> {
+    /// Error type
+    type Error;
+
+    /// Sends `words` to the slave, ignoring all the incoming words
+    fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+    where
+        WI: IntoIterator<Item = W>;
+}
+
+/// Blocking flush
+///
+/// Like the other blocking traits this is generic over the word type, so that implementations
+/// over `spi::FullDuplex<W>` can report its error type. It defaults to `u8`.
+pub trait Flush<W = u8> {
+    /// Error type
+    type Error;
+
+    /// Blocks until all the words written so far have been shifted out on the bus
+    ///
+    /// Implementations that queue words, e.g. in a FIFO or through DMA, may return from `try_write`
+    /// while the last words are still being sent. This must be called before e.g. releasing an
+    /// external latch or entering a low power mode that stops the SPI peripheral.
+    fn try_flush(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Blocking half duplex (3-wire) write followed by read
+pub trait ThreeWire<W> {
+    /// Error type
+    type Error;
+
+    /// Sends `write` to the slave, then switches the data line direction and fills `read` with
+    /// the words received from the slave
+    ///
+    /// Either buffer may be empty, in which case the corresponding phase is skipped.
+    fn try_write_read(&mut self, write: &[W], read: &mut [W]) -> Result<(), Self::Error>;
+}
+
+/// Marker trait indicating that chip select is managed by the implementer
+///
+/// Implementers of this trait assert the chip select line before, and release it after, each
+/// call to one of the blocking SPI traits, so every call is a complete transaction with the
+/// device. The line is only released once all the words have been shifted out, see [`Flush`].
+/// Drivers that rely on this behaviour rather than managing the chip select line themselves
+/// should require this trait.
+pub trait ManagedCs {}
+
+/// Blocking transfer
+pub mod transfer {
+    /// Default implementation of `blocking::spi::Transfer<W>` for implementers of
+    /// `spi::FullDuplex<W>`
+    pub trait Default<W>: crate::spi::FullDuplex<W> {}
+
+    impl<W, S> crate::blocking::spi::Transfer<W> for S
+    where
+        S: Default<W>,
+        W: Clone,
+    {
+        type Error = S::Error;
+
+        fn try_transfer<'w>(&mut self, words: &'w mut [W]) -> Result<&'w [W], S::Error> {
+            for word in words.iter_mut() {
+                nb::block!(self.try_send(word.clone()))?;
+                *word = nb::block!(self.try_read())?;
+            }
+
+            Ok(words)
+        }
+    }
+}
+
+/// Blocking write
+pub mod write {
+    /// Default implementation of `blocking::spi::Write<W>` for implementers of `spi::FullDuplex<W>`
+    pub trait Default<W>: crate::spi::FullDuplex<W> {}
+
+    impl<W, S> crate::blocking::spi::Write<W> for S
+    where
+        S: Default<W>,
+        W: Clone,
+    {
+        type Error = S::Error;
+
+        fn try_write(&mut self, words: &[W]) -> Result<(), S::Error> {
+            for word in words {
+                nb::block!(self.try_send(word.clone()))?;
+                nb::block!(self.try_read())?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Blocking write (iterator version)
+pub mod write_iter {
+    /// Default implementation of `blocking::spi::WriteIter<W>` for implementers of
+    /// `spi::FullDuplex<W>`
+    pub trait Default<W>: crate::spi::FullDuplex<W> {}
+
+    impl<W, S> crate::blocking::spi::WriteIter<W> for S
+    where
+        S: Default<W>,
+        W: Clone,
+    {
+        type Error = S::Error;
+
+        fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), S::Error>
+        where
+            WI: IntoIterator<Item = W>,
+        {
+            for word in words.into_iter() {
+                nb::block!(self.try_send(word.clone()))?;
+                nb::block!(self.try_read())?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Blocking flush
+pub mod flush {
+    /// Default implementation of `blocking::spi::Flush<W>` for implementers of
+    /// `spi::FullDuplex<W>`
+    ///
+    /// The other `Default` implementations wait for each word to be received back, so the words
+    /// written have always been shifted out already and flushing does nothing.
+    pub trait Default<W>: crate::spi::FullDuplex<W> {}
+
+    impl<W, S> crate::blocking::spi::Flush<W> for S
+    where
+        S: Default<W>,
+    {
+        type Error = S::Error;
+
+        fn try_flush(&mut self) -> Result<(), S::Error> {
+            Ok(())
+        }
+    }
+}
+
+/// Blocking half duplex (3-wire) write followed by read
+pub mod three_wire {
+    use crate::spi::Direction;
+
+    /// Default implementation of `blocking::spi::ThreeWire<W>` for implementers of
+    /// `spi::HalfDuplex<W>`
+    pub trait Default<W>: crate::spi::HalfDuplex<W> {}
+
+    impl<W, S> crate::blocking::spi::ThreeWire<W> for S
+    where
+        S: Default<W>,
+        W: Clone,
+    {
+        type Error = S::Error;
+
+        fn try_write_read(&mut self, write: &[W], read: &mut [W]) -> Result<(), S::Error> {
+            if !write.is_empty() {
+                nb::block!(self.try_set_direction(Direction::Transmit))?;
+                for word in write {
+                    nb::block!(self.try_send(word.clone()))?;
+                }
+            }
+
+            if !read.is_empty() {
+                nb::block!(self.try_set_direction(Direction::Receive))?;
+                for word in read.iter_mut() {
+                    *word = nb::block!(self.try_read())?;
+                }
+            } else {
+                nb::block!(self.try_flush())?;
+            }
+
+            Ok(())
+        }
+    }
+}
+
+/// Operation for transactional SPI trait
+///
+/// This allows composition of SPI operations into a single bus transaction
+///
+/// ```
+/// use embedded_hal::blocking::spi::{Operation, Transactional};
+/// # let mut spi = embedded_hal::loopback::Spi::new();
+///
+/// // fast read command, a dummy byte, then the data into a separate buffer
+/// let mut data = [0u8; 16];
+/// spi.try_exec(&mut [
+///     Operation::Write(&[0x0B, 0x00, 0x10, 0x00]),
+///     Operation::Write(&[0x00]),
+///     Operation::Read(&mut data),
+/// ])
+/// .unwrap();
+/// ```
+#[derive(Debug, PartialEq)]
+pub enum Operation<'a, W: 'static> {
+    /// Read data into the provided buffer
+    ///
+    /// The words written meanwhile are implementation defined, the default implementation
+    /// sends `W::default()`, i.e. zeroes.
+    Read(&'a mut [W]),
+    /// Write data from the provided buffer, discarding read data
+    Write(&'a [W]),
+    /// Write data from the second buffer while reading data into the first one
+    ///
+    /// The buffers may differ in length, the operation then lasts for the longer of the two:
+    /// extra read words are handled as in `Read` and extra written words as in `Write`.
+    Transfer(&'a mut [W], &'a [W]),
+    /// Write data out of the provided buffer while reading data into it
+    TransferInPlace(&'a mut [W]),
+    /// Wait for the given number of microseconds, keeping chip select asserted
+    ///
+    /// Waiting requires a delay implementation, so this is usually executed by the chip select
+    /// management, e.g. [`SpiWithCs::with_delay`](spi_with_cs::SpiWithCs::with_delay), rather than
+    /// by the bus. [`SpiWithCs`](spi_with_cs::SpiWithCs) without a delay returns an error.
+    ///
+    /// Buses have no time base, so the `Default` implementation of [`Transactional`] only waits
+    /// for the preceding operations to complete: the delay lasts as long as the gap between the
+    /// surrounding operations.
+    DelayUs(u32),
+}
+
+/// Transactional trait allows multiple actions to be executed
+/// as part of a single SPI transaction
+pub trait Transactional<W: 'static> {
+    /// Associated error type
+    type Error;
+
+    /// Execute the provided transactions
+    fn try_exec<'a>(&mut self, operations: &mut [Operation<'a, W>]) -> Result<(), Self::Error>;
+}
+
+/// Blocking transactional impl over spi::Write and spi::Transfer
+pub mod transactional {
+    use super::{Operation, Transfer, Write};
+
+    /// Default implementation of `blocking::spi::Transactional<W>` for implementers of
+    /// `spi::Write<W>` and `spi::Transfer<W>`
+    pub trait Default<W>: Write<W> + Transfer<W> {}
+
+    impl<W: 'static, E, S> super::Transactional<W> for S
+    where
+        S: self::Default<W> + Write<W, Error = E> + Transfer<W, Error = E>,
+        W: Copy + Clone + core::default::Default,
+    {
+        type Error = E;
+
+        fn try_exec<'a>(&mut self, operations: &mut [super::Operation<'a, W>]) -> Result<(), E> {
+            for op in operations {
+                match op {
+                    Operation::Read(r) => {
+                        r.fill(W::default());
+                        self.try_transfer(r)?;
+                    }
+                    Operation::Write(w) => self.try_write(w)?,
+                    Operation::Transfer(r, w) if r.len() >= w.len() => {
+                        let (head, tail) = r.split_at_mut(w.len());
+                        head.copy_from_slice(w);
+                        tail.fill(W::default());
+                        self.try_transfer(r)?;
+                    }
+                    Operation::Transfer(r, w) => {
+                        let (head, tail) = w.split_at(r.len());
+                        r.copy_from_slice(head);
+                        self.try_transfer(r)?;
+                        self.try_write(tail)?;
+                    }
+                    Operation::TransferInPlace(t) => self.try_transfer(t).map(|_| ())?,
+                    // the blocking operations above have already completed
+                    Operation::DelayUs(_) => {}
+                }
+            }
+
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Byte oriented access to SPI buses configured for wider words
+//!
+//! Some devices expect 16 or 32-bit frames while drivers naturally deal in bytes. The adapters
+//! in this module implement the blocking SPI traits for 8-bit words on top of an implementation
+//! for 16-bit ([`Packed16`]) or 32-bit ([`Packed32`]) words, packing consecutive bytes into a
+//! single word with the first byte in the most significant position. With a MSB first bus this
+//! produces the same bit stream on the wire as sending the bytes individually.
+//!
+//! The number of bytes passed to each call must be a multiple of the word size, otherwise
+//! [`PackedError::Length`] is returned and nothing is sent.
+//!
+//! Data is forwarded to the inner bus in chunks of up to 32 words, so chip select has to be
+//! managed around the adapter, e.g. with [`SpiWithCs`](super::spi_with_cs::SpiWithCs), rather
+//! than by the inner bus.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::spi::packed::Packed16;
+//! use embedded_hal::blocking::spi::{transfer, write, Transfer};
+//! use embedded_hal::spi::FullDuplex;
+//! # use core::convert::Infallible;
+//!
+//! /// SPI peripheral configured for 16-bit frames, here looping MOSI back to MISO
+//! struct Spi16 {
+//!     shift: u16,
+//! }
+//!
+//! impl FullDuplex<u16> for Spi16 {
+//!     type Error = Infallible;
+//!
+//!     fn try_read(&mut self) -> nb::Result<u16, Self::Error> {
+//!         Ok(self.shift)
+//!     }
+//!
+//!     fn try_send(&mut self, word: u16) -> nb::Result<(), Self::Error> {
+//!         self.shift = word;
+//!         Ok(())
+//!     }
+//! }
+//!
+//! impl transfer::Default<u16> for Spi16 {}
+//! impl write::Default<u16> for Spi16 {}
+//!
+//! let mut spi = Packed16::new(Spi16 { shift: 0 });
+//!
+//! let mut buf = [0x12, 0x34, 0x56, 0x78];
+//! assert_eq!(spi.try_transfer(&mut buf).unwrap(), &[0x12, 0x34, 0x56, 0x78]);
+//! ```
+
+use super::{Flush, Transfer, Write};
+
+/// Number of words forwarded to the inner bus per call
+const CHUNK_WORDS: usize = 32;
+
+wrapper_error! {
+    /// Error type of the packing adapters
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum PackedError<E> {
+        /// Underlying SPI communication error
+        Spi(E) => forward, "SPI error",
+        /// The number of bytes is not a multiple of the word size
+        Length => Other, "length is not a multiple of the word size",
+    }
+
+    impl crate::spi::Error => crate::spi::ErrorKind where E: crate::spi::Error
+}
+
+macro_rules! impl_packed {
+    ($(#[$meta:meta])* $name:ident, $W:ty, $N:expr) => {
+        $(#[$meta])*
+        pub struct $name<Spi> {
+            spi: Spi,
+        }
+
+        impl<Spi> $name<Spi> {
+            /// Wraps `spi`
+            pub fn new(spi: Spi) -> Self {
+                Self { spi }
+            }
+
+            /// Destroys the adapter, returning the SPI bus
+            pub fn destroy(self) -> Spi {
+                self.spi
+            }
+        }
+
+        impl<Spi: Flush<$W>> Flush for $name<Spi> {
+            type Error = PackedError<Spi::Error>;
+
+            fn try_flush(&mut self) -> Result<(), Self::Error> {
+                self.spi.try_flush().map_err(PackedError::Spi)
+            }
+        }
+
+        impl<Spi> Transfer<u8> for $name<Spi>
+        where
+            Spi: Transfer<$W>,
+        {
+            type Error = PackedError<Spi::Error>;
+
+            fn try_transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+                if words.len() % $N != 0 {
+                    return Err(PackedError::Length);
+                }
+
+                for chunk in words.chunks_mut(CHUNK_WORDS * $N) {
+                    let mut buf = [0; CHUNK_WORDS];
+                    let buf = &mut buf[..chunk.len() / $N];
+                    for (word, bytes) in buf.iter_mut().zip(chunk.chunks($N)) {
+                        let mut b = [0; $N];
+                        b.copy_from_slice(bytes);
+                        *word = <$W>::from_be_bytes(b);
+                    }
+
+                    let received = self.spi.try_transfer(buf).map_err(PackedError::Spi)?;
+
+                    for (word, bytes) in received.iter().zip(chunk.chunks_mut($N)) {
+                        bytes.copy_from_slice(&word.to_be_bytes());
+                    }
+                }
+
+                Ok(words)
+            }
+        }
+
+        impl<Spi> Write<u8> for $name<Spi>
+        where
+            Spi: Write<$W>,
+        {
+            type Error = PackedError<Spi::Error>;
+
+            fn try_write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+                if words.len() % $N != 0 {
+                    return Err(PackedError::Length);
+                }
+
+                for chunk in words.chunks(CHUNK_WORDS * $N) {
+                    let mut buf = [0; CHUNK_WORDS];
+                    let buf = &mut buf[..chunk.len() / $N];
+                    for (word, bytes) in buf.iter_mut().zip(chunk.chunks($N)) {
+                        let mut b = [0; $N];
+                        b.copy_from_slice(bytes);
+                        *word = <$W>::from_be_bytes(b);
+                    }
+
+                    self.spi.try_write(buf).map_err(PackedError::Spi)?;
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_packed!(
+    /// Implements the blocking SPI traits for bytes on top of a 16-bit word implementation
+    Packed16,
+    u16,
+    2
+);
+
+impl_packed!(
+    /// Implements the blocking SPI traits for bytes on top of a 32-bit word implementation
+    Packed32,
+    u32,
+    4
+);
@@ -0,0 +1,155 @@
+//! Retrying of failed SPI operations
+//!
+//! Marginal wiring or a busy shared bus can make operations fail transiently, e.g. with an
+//! overrun. [`SpiRetry`] repeats a failed operation up to a configured number of times, waiting
+//! between attempts, and only reports the error of the last attempt.
+//!
+//! Writes are retried transparently through the [`Write`] trait. A failed transfer may already
+//! have overwritten part of the outgoing words with received ones, so [`Transfer`](super::Transfer)
+//! is not implemented; use [`retry`](SpiRetry::retry) instead and refill the buffer in the
+//! closure.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::spi::retry::SpiRetry;
+//! use embedded_hal::blocking::spi::{Transfer, Write};
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::delay::DelayUs;
+//! # struct Spi;
+//! # impl Write<u8> for Spi {
+//! #     type Error = Infallible;
+//! #     fn try_write(&mut self, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl Transfer<u8> for Spi {
+//! #     type Error = Infallible;
+//! #     fn try_transfer<'w>(&mut self, w: &'w mut [u8]) -> Result<&'w [u8], Self::Error> { Ok(w) }
+//! # }
+//! # struct Delay;
+//! # impl DelayUs<u32> for Delay {
+//! #     type Error = Infallible;
+//! #     fn try_delay_us(&mut self, _: u32) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (spi, delay) = (Spi, Delay);
+//!
+//! // up to 3 retries, 100 us apart
+//! let mut spi = SpiRetry::new(spi, delay, 3, 100);
+//! spi.try_write(&[0x06]).unwrap();
+//!
+//! let mut buf = [0; 3];
+//! spi.retry(|spi| {
+//!     buf = [0x9F, 0x00, 0x00];
+//!     spi.try_transfer(&mut buf).map(|_| ())
+//! })
+//! .unwrap();
+//! ```
+
+use core::fmt::Debug;
+
+use super::{Flush, Write};
+use crate::blocking::delay::DelayUs;
+
+/// Wraps a blocking SPI implementation, retrying failed operations
+///
+/// [`Write`] is implemented for 8, 16 and 32-bit words.
+///
+/// Chip select should be managed by the inner bus, e.g. with
+/// [`SpiWithCs`](super::spi_with_cs::SpiWithCs), so that every attempt is a complete
+/// transaction with the device. The wrapper does not implement [`ManagedCs`](super::ManagedCs)
+/// itself as it can't tell whether the inner bus does.
+pub struct SpiRetry<Spi, Delay> {
+    spi: Spi,
+    delay: Delay,
+    retries: usize,
+    delay_us: u32,
+}
+
+wrapper_error! {
+    /// Error type combining SPI and delay errors
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum SpiRetryError<SpiError, DelayError> {
+        /// Error of the last attempt
+        Spi(SpiError) => forward, "SPI error after retrying",
+        /// Underlying delay error
+        Delay(DelayError) => Other, "delay error",
+    }
+
+    impl crate::spi::Error => crate::spi::ErrorKind where SpiError: crate::spi::Error
+}
+
+impl<Spi, Delay> SpiRetry<Spi, Delay>
+where
+    Delay: DelayUs<u32>,
+{
+    /// Creates a new wrapper retrying failed operations up to `retries` times, waiting
+    /// `delay_us` microseconds before each retry
+    pub fn new(spi: Spi, delay: Delay, retries: usize, delay_us: u32) -> Self {
+        Self {
+            spi,
+            delay,
+            retries,
+            delay_us,
+        }
+    }
+
+    /// Destroys the wrapper, returning the SPI bus and delay
+    pub fn destroy(self) -> (Spi, Delay) {
+        (self.spi, self.delay)
+    }
+
+    /// Runs `f` on the inner bus, running it again after the configured delay as long as it
+    /// fails and retries remain
+    ///
+    /// `f` has to set up any buffers itself, as a failed attempt may have modified them.
+    pub fn retry<R, E, F>(&mut self, mut f: F) -> Result<R, SpiRetryError<E, Delay::Error>>
+    where
+        F: FnMut(&mut Spi) -> Result<R, E>,
+    {
+        let mut retries = self.retries;
+
+        loop {
+            match f(&mut self.spi) {
+                Ok(r) => return Ok(r),
+                Err(e) if retries == 0 => return Err(SpiRetryError::Spi(e)),
+                Err(_) => {
+                    retries -= 1;
+                    self.delay
+                        .try_delay_us(self.delay_us)
+                        .map_err(SpiRetryError::Delay)?;
+                }
+            }
+        }
+    }
+}
+
+macro_rules! impl_retry {
+    ($($W:ty),*) => {
+        $(
+            impl<Spi, Delay> Flush<$W> for SpiRetry<Spi, Delay>
+            where
+                Spi: Flush<$W>,
+                Delay: DelayUs<u32>,
+            {
+                type Error = SpiRetryError<Spi::Error, Delay::Error>;
+
+                fn try_flush(&mut self) -> Result<(), Self::Error> {
+                    self.retry(|spi| spi.try_flush())
+                }
+            }
+
+            impl<Spi, Delay> Write<$W> for SpiRetry<Spi, Delay>
+            where
+                Spi: Write<$W>,
+                Delay: DelayUs<u32>,
+            {
+                type Error = SpiRetryError<Spi::Error, Delay::Error>;
+
+                fn try_write(&mut self, words: &[$W]) -> Result<(), Self::Error> {
+                    self.retry(|spi| spi.try_write(words))
+                }
+            }
+        )*
+    };
+}
+
+impl_retry!(u8, u16, u32);
@@ -0,0 +1,157 @@
+//! Sharing a single SPI bus between several devices
+//!
+//! A [`SharedBus`] takes ownership of an SPI bus and hands out one [`SpiWithCs`] device handle
+//! per chip select pin. Each handle implements the blocking SPI traits and [`ManagedCs`], so
+//! drivers for different devices can each own their handle while using the same bus.
+//!
+//! The bus is borrowed through a `RefCell` for the duration of each operation, so the handles
+//! can only be used from a single execution context. Sharing a bus between the main thread and
+//! interrupt handlers requires additional synchronization.
+//!
+//! [`ManagedCs`]: super::ManagedCs
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::spi::shared::SharedBus;
+//! use embedded_hal::blocking::spi::Write;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::digital::OutputPin;
+//! # struct Spi;
+//! # impl embedded_hal::blocking::spi::Flush for Spi {
+//! #     type Error = Infallible;
+//! #     fn try_flush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl Write<u8> for Spi {
+//! #     type Error = Infallible;
+//! #     fn try_write(&mut self, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # struct Pin;
+//! # impl OutputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (spi, display_cs, sd_card_cs) = (Spi, Pin, Pin);
+//!
+//! let bus = SharedBus::new(spi);
+//!
+//! let mut display = bus.device(display_cs);
+//! let mut sd_card = bus.device(sd_card_cs);
+//!
+//! display.try_write(&[0x2C, 0x00, 0x00]).unwrap();
+//! sd_card.try_write(&[0x40, 0x00, 0x00, 0x00, 0x00, 0x95]).unwrap();
+//! ```
+
+use core::cell::RefCell;
+
+use super::spi_with_cs::SpiWithCs;
+use super::{Flush, Operation, Transactional, Transfer, Write, WriteIter};
+use crate::digital::OutputPin;
+
+/// SPI bus shared between several devices
+pub struct SharedBus<Spi> {
+    spi: RefCell<Spi>,
+}
+
+impl<Spi> SharedBus<Spi> {
+    /// Creates a new shared bus from the provided SPI bus
+    pub fn new(spi: Spi) -> Self {
+        Self {
+            spi: RefCell::new(spi),
+        }
+    }
+
+    /// Creates a handle for the device selected by the `cs` pin
+    pub fn device<Pin>(&self, cs: Pin) -> SpiWithCs<BusProxy<'_, Spi>, Pin>
+    where
+        Pin: OutputPin,
+    {
+        SpiWithCs::new(BusProxy { spi: &self.spi }, cs)
+    }
+
+    /// Destroys the shared bus, returning the SPI bus
+    pub fn destroy(self) -> Spi {
+        self.spi.into_inner()
+    }
+}
+
+/// Borrowed access to a [`SharedBus`]
+///
+/// This does not manage any chip select line by itself, use [`SharedBus::device`] to obtain a
+/// handle for a specific device.
+pub struct BusProxy<'a, Spi> {
+    spi: &'a RefCell<Spi>,
+}
+
+macro_rules! impl_bus_proxy {
+    ($($W:ty),*) => {
+        $(
+            impl<'a, Spi> Flush<$W> for BusProxy<'a, Spi>
+            where
+                Spi: Flush<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_flush(&mut self) -> Result<(), Self::Error> {
+                    self.spi.borrow_mut().try_flush()
+                }
+            }
+
+            impl<'a, Spi> Transfer<$W> for BusProxy<'a, Spi>
+            where
+                Spi: Transfer<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_transfer<'w>(
+                    &mut self,
+                    words: &'w mut [$W],
+                ) -> Result<&'w [$W], Self::Error> {
+                    self.spi.borrow_mut().try_transfer(words)
+                }
+            }
+
+            impl<'a, Spi> Write<$W> for BusProxy<'a, Spi>
+            where
+                Spi: Write<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_write(&mut self, words: &[$W]) -> Result<(), Self::Error> {
+                    self.spi.borrow_mut().try_write(words)
+                }
+            }
+
+            impl<'a, Spi> WriteIter<$W> for BusProxy<'a, Spi>
+            where
+                Spi: WriteIter<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+                where
+                    WI: IntoIterator<Item = $W>,
+                {
+                    self.spi.borrow_mut().try_write_iter(words)
+                }
+            }
+
+            impl<'a, Spi> Transactional<$W> for BusProxy<'a, Spi>
+            where
+                Spi: Transactional<$W>,
+            {
+                type Error = Spi::Error;
+
+                fn try_exec<'o>(
+                    &mut self,
+                    operations: &mut [Operation<'o, $W>],
+                ) -> Result<(), Self::Error> {
+                    self.spi.borrow_mut().try_exec(operations)
+                }
+            }
+        )*
+    };
+}
+
+impl_bus_proxy!(u8, u16, u32);
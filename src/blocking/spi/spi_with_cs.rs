@@ -0,0 +1,475 @@
+//! Chip select management for an exclusively owned SPI bus
+
+use core::convert::Infallible;
+use core::fmt::Debug;
+
+use super::full_duplex::BlockingFullDuplex;
+use super::{Flush, ManagedCs, Operation, Transactional, Transfer, Write, WriteIter};
+use crate::blocking::delay::DelayUs;
+use crate::digital::OutputPin;
+
+/// Wraps a blocking SPI implementation and an active low chip select pin
+///
+/// The chip select pin is asserted for the duration of each call to one of the blocking SPI
+/// traits, so the wrapper implements [`ManagedCs`]. To share a bus between several devices see
+/// the [`shared`](super::shared) module.
+///
+/// Slow devices that require a gap between consecutive words can be accommodated with
+/// [`with_word_delay`](SpiWithCs::with_word_delay). Words are then sent to the inner bus one at
+/// a time, waiting for the configured delay between them.
+///
+/// The blocking SPI traits, including `WriteIter`, are implemented for 8, 16 and 32-bit words.
+/// They can't be implemented for any word type as such impls would overlap with the `Default`
+/// implementations for `spi::FullDuplex` implementers.
+pub struct SpiWithCs<Spi, Pin, Delay = NoDelay> {
+    spi: Spi,
+    cs: Pin,
+    delay: Delay,
+    word_delay_us: u32,
+}
+
+wrapper_error! {
+    /// Error type combining SPI, chip select pin and delay errors
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum SpiWithCsError<SpiError, PinError, DelayError = Infallible> {
+        /// Underlying SPI communication error
+        Spi(SpiError) => forward, "SPI error",
+        /// Underlying chip select pin state setting error
+        Pin(PinError) => ChipSelectFault, "chip select pin error",
+        /// Underlying delay error
+        Delay(DelayError) => Other, "delay error",
+    }
+
+    impl crate::spi::Error => crate::spi::ErrorKind where SpiError: crate::spi::Error
+}
+
+/// Placeholder delay used when no delay is configured
+///
+/// Waiting for zero microseconds succeeds, while waiting for a non-zero duration fails with
+/// [`NoDelayError`], e.g. when executing an `Operation::DelayUs` with a [`SpiWithCs`] that has no
+/// delay. Configure one with [`with_delay`](SpiWithCs::with_delay).
+pub struct NoDelay;
+
+/// Error of [`NoDelay`], returned when waiting without a configured delay
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NoDelayError;
+
+impl core::fmt::Display for NoDelayError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "no delay configured")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NoDelayError {}
+
+impl DelayUs<u32> for NoDelay {
+    type Error = NoDelayError;
+
+    fn try_delay_us(&mut self, us: u32) -> Result<(), Self::Error> {
+        if us == 0 {
+            Ok(())
+        } else {
+            Err(NoDelayError)
+        }
+    }
+}
+
+impl<Spi, Pin, Delay> ManagedCs for SpiWithCs<Spi, Pin, Delay> {}
+
+impl<Spi, Pin> SpiWithCs<Spi, Pin>
+where
+    Pin: OutputPin,
+{
+    /// Creates a new wrapper from the provided SPI bus and chip select pin
+    pub fn new(spi: Spi, cs: Pin) -> Self {
+        Self {
+            spi,
+            cs,
+            delay: NoDelay,
+            word_delay_us: 0,
+        }
+    }
+
+    /// Configures a delay of `us` microseconds between consecutive words
+    ///
+    /// The delay is inserted between all the words of a single call, including the words of
+    /// different operations of a transaction. It is also used to execute `Operation::DelayUs`, as
+    /// with [`with_delay`](SpiWithCs::with_delay).
+    ///
+    /// ```
+    /// use embedded_hal::blocking::spi::spi_with_cs::SpiWithCs;
+    /// use embedded_hal::blocking::spi::Write;
+    /// # use core::convert::Infallible;
+    /// # use embedded_hal::blocking::delay::DelayUs;
+    /// # use embedded_hal::digital::OutputPin;
+    /// # struct Spi;
+    /// # impl embedded_hal::blocking::spi::Flush for Spi {
+    /// #     type Error = Infallible;
+    /// #     fn try_flush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # impl Write<u8> for Spi {
+    /// #     type Error = Infallible;
+    /// #     fn try_write(&mut self, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # struct Pin;
+    /// # impl OutputPin for Pin {
+    /// #     type Error = Infallible;
+    /// #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # struct Delay;
+    /// # impl DelayUs<u32> for Delay {
+    /// #     type Error = Infallible;
+    /// #     fn try_delay_us(&mut self, _: u32) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # let (spi, cs, delay) = (Spi, Pin, Delay);
+    /// // wait 10 us between words
+    /// let mut eeprom = SpiWithCs::new(spi, cs).with_word_delay(delay, 10);
+    ///
+    /// eeprom.try_write(&[0x02, 0x00, 0x10, 0xAA]).unwrap();
+    /// ```
+    pub fn with_word_delay<Delay>(self, delay: Delay, us: u32) -> SpiWithCs<Spi, Pin, Delay>
+    where
+        Delay: DelayUs<u32>,
+    {
+        SpiWithCs {
+            spi: self.spi,
+            cs: self.cs,
+            delay,
+            word_delay_us: us,
+        }
+    }
+
+    /// Configures the delay used to execute `Operation::DelayUs` in transactions
+    ///
+    /// The delay operations are executed by the wrapper, with chip select asserted, and never
+    /// reach the inner bus.
+    ///
+    /// ```
+    /// use embedded_hal::blocking::spi::spi_with_cs::SpiWithCs;
+    /// use embedded_hal::blocking::spi::{Operation, Transactional};
+    /// # use core::convert::Infallible;
+    /// # use embedded_hal::blocking::delay::DelayUs;
+    /// # use embedded_hal::digital::OutputPin;
+    /// # struct Pin;
+    /// # impl OutputPin for Pin {
+    /// #     type Error = Infallible;
+    /// #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # struct Delay;
+    /// # impl DelayUs<u32> for Delay {
+    /// #     type Error = Infallible;
+    /// #     fn try_delay_us(&mut self, _: u32) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # let (spi, cs, delay) = (embedded_hal::loopback::Spi::new(), Pin, Delay);
+    /// let mut adc = SpiWithCs::new(spi, cs).with_delay(delay);
+    ///
+    /// // start a conversion, wait for it to complete and read the result
+    /// let mut result = [0u8; 2];
+    /// adc.try_exec(&mut [
+    ///     Operation::Write(&[0x08]),
+    ///     Operation::DelayUs(50),
+    ///     Operation::Read(&mut result),
+    /// ])
+    /// .unwrap();
+    /// ```
+    pub fn with_delay<Delay>(self, delay: Delay) -> SpiWithCs<Spi, Pin, Delay>
+    where
+        Delay: DelayUs<u32>,
+    {
+        self.with_word_delay(delay, 0)
+    }
+}
+
+impl<Spi, Pin> SpiWithCs<BlockingFullDuplex<Spi>, Pin>
+where
+    Pin: OutputPin,
+{
+    /// Creates a new wrapper from a `spi::FullDuplex` implementation and a chip select pin
+    ///
+    /// The blocking SPI traits are provided by [`BlockingFullDuplex`], so `spi` doesn't need to
+    /// opt into their `Default` implementations.
+    pub fn from_full_duplex(spi: Spi, cs: Pin) -> Self {
+        Self::new(BlockingFullDuplex::new(spi), cs)
+    }
+}
+
+impl<Spi, Pin, Delay> SpiWithCs<Spi, Pin, Delay>
+where
+    Pin: OutputPin,
+    Delay: DelayUs<u32>,
+{
+    /// Returns mutable references to the inner SPI bus and chip select pin
+    ///
+    /// Note that using these directly bypasses the chip select management of this wrapper.
+    pub fn inner(&mut self) -> (&mut Spi, &mut Pin) {
+        (&mut self.spi, &mut self.cs)
+    }
+
+    /// Destroys the wrapper, returning the SPI bus, chip select pin and delay
+    pub fn destroy(self) -> (Spi, Pin, Delay) {
+        (self.spi, self.cs, self.delay)
+    }
+
+    /// Removes the delay, returning the wrapper and the delay
+    pub fn without_word_delay(self) -> (SpiWithCs<Spi, Pin>, Delay) {
+        (
+            SpiWithCs {
+                spi: self.spi,
+                cs: self.cs,
+                delay: NoDelay,
+                word_delay_us: 0,
+            },
+            self.delay,
+        )
+    }
+
+    /// Runs `f` with the chip select pin asserted, releasing it afterwards even if `f` fails
+    ///
+    /// The bus is flushed before releasing the pin, so that the last words have been shifted
+    /// out.
+    fn with_cs<W, R, SpiError, F>(
+        &mut self,
+        f: F,
+    ) -> Result<R, SpiWithCsError<SpiError, Pin::Error, Delay::Error>>
+    where
+        Spi: Flush<W, Error = SpiError>,
+        F: FnOnce(&mut Self) -> Result<R, SpiWithCsError<SpiError, Pin::Error, Delay::Error>>,
+    {
+        self.cs.try_set_low().map_err(SpiWithCsError::Pin)?;
+
+        let res = f(self);
+        let flushed = self.spi.try_flush().map_err(SpiWithCsError::Spi);
+
+        self.cs.try_set_high().map_err(SpiWithCsError::Pin)?;
+
+        let res = res?;
+        flushed?;
+
+        Ok(res)
+    }
+
+    /// Waits for `us` microseconds once the words written so far have been shifted out
+    fn flush_delay_us<W, SpiError>(
+        &mut self,
+        us: u32,
+    ) -> Result<(), SpiWithCsError<SpiError, Pin::Error, Delay::Error>>
+    where
+        Spi: Flush<W, Error = SpiError>,
+    {
+        self.spi.try_flush().map_err(SpiWithCsError::Spi)?;
+        self.delay_us(us)
+    }
+
+    /// Waits for the inter-word delay unless this is the first word of the call
+    fn word_gap<W, SpiError>(
+        &mut self,
+        first: &mut bool,
+    ) -> Result<(), SpiWithCsError<SpiError, Pin::Error, Delay::Error>>
+    where
+        Spi: Flush<W, Error = SpiError>,
+    {
+        if !*first {
+            self.flush_delay_us(self.word_delay_us)?;
+        }
+        *first = false;
+
+        Ok(())
+    }
+
+    fn delay_us<SpiError>(
+        &mut self,
+        us: u32,
+    ) -> Result<(), SpiWithCsError<SpiError, Pin::Error, Delay::Error>> {
+        self.delay.try_delay_us(us).map_err(SpiWithCsError::Delay)
+    }
+}
+
+macro_rules! impl_spi_with_cs {
+    ($($W:ty),*) => {
+        $(
+            impl<Spi, Pin, Delay> Flush<$W> for SpiWithCs<Spi, Pin, Delay>
+            where
+                Spi: Flush<$W>,
+                Pin: OutputPin,
+                Delay: DelayUs<u32>,
+            {
+                type Error = SpiWithCsError<Spi::Error, Pin::Error, Delay::Error>;
+
+                fn try_flush(&mut self) -> Result<(), Self::Error> {
+                    self.spi.try_flush().map_err(SpiWithCsError::Spi)
+                }
+            }
+
+            impl<Spi, Pin, Delay, E> Transfer<$W> for SpiWithCs<Spi, Pin, Delay>
+            where
+                Spi: Transfer<$W, Error = E> + Flush<$W, Error = E>,
+                Pin: OutputPin,
+                Delay: DelayUs<u32>,
+            {
+                type Error = SpiWithCsError<E, Pin::Error, Delay::Error>;
+
+                fn try_transfer<'w>(
+                    &mut self,
+                    words: &'w mut [$W],
+                ) -> Result<&'w [$W], Self::Error> {
+                    self.with_cs(move |s| {
+                        if s.word_delay_us == 0 {
+                            return s.spi.try_transfer(words).map_err(SpiWithCsError::Spi);
+                        }
+
+                        let mut first = true;
+                        for word in words.chunks_mut(1) {
+                            s.word_gap(&mut first)?;
+                            s.spi.try_transfer(word).map_err(SpiWithCsError::Spi)?;
+                        }
+
+                        Ok(words)
+                    })
+                }
+            }
+
+            impl<Spi, Pin, Delay, E> Write<$W> for SpiWithCs<Spi, Pin, Delay>
+            where
+                Spi: Write<$W, Error = E> + Flush<$W, Error = E>,
+                Pin: OutputPin,
+                Delay: DelayUs<u32>,
+            {
+                type Error = SpiWithCsError<E, Pin::Error, Delay::Error>;
+
+                fn try_write(&mut self, words: &[$W]) -> Result<(), Self::Error> {
+                    self.with_cs(|s| {
+                        if s.word_delay_us == 0 {
+                            return s.spi.try_write(words).map_err(SpiWithCsError::Spi);
+                        }
+
+                        let mut first = true;
+                        for word in words.chunks(1) {
+                            s.word_gap(&mut first)?;
+                            s.spi.try_write(word).map_err(SpiWithCsError::Spi)?;
+                        }
+
+                        Ok(())
+                    })
+                }
+            }
+
+            impl<Spi, Pin, Delay, E> WriteIter<$W> for SpiWithCs<Spi, Pin, Delay>
+            where
+                Spi: WriteIter<$W, Error = E> + Flush<$W, Error = E>,
+                Pin: OutputPin,
+                Delay: DelayUs<u32>,
+            {
+                type Error = SpiWithCsError<E, Pin::Error, Delay::Error>;
+
+                fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+                where
+                    WI: IntoIterator<Item = $W>,
+                {
+                    self.with_cs(|s| {
+                        if s.word_delay_us == 0 {
+                            return s.spi.try_write_iter(words).map_err(SpiWithCsError::Spi);
+                        }
+
+                        let mut first = true;
+                        for word in words {
+                            s.word_gap(&mut first)?;
+                            s.spi
+                                .try_write_iter(core::iter::once(word))
+                                .map_err(SpiWithCsError::Spi)?;
+                        }
+
+                        Ok(())
+                    })
+                }
+            }
+
+            impl<Spi, Pin, Delay, E> Transactional<$W> for SpiWithCs<Spi, Pin, Delay>
+            where
+                Spi: Transactional<$W, Error = E> + Flush<$W, Error = E>,
+                Pin: OutputPin,
+                Delay: DelayUs<u32>,
+            {
+                type Error = SpiWithCsError<E, Pin::Error, Delay::Error>;
+
+                fn try_exec<'a>(
+                    &mut self,
+                    operations: &mut [Operation<'a, $W>],
+                ) -> Result<(), Self::Error> {
+                    self.with_cs(|s| {
+                        if s.word_delay_us == 0 {
+                            let mut rest = operations;
+                            while !rest.is_empty() {
+                                let end = rest
+                                    .iter()
+                                    .position(|op| matches!(op, Operation::DelayUs(_)))
+                                    .unwrap_or(rest.len());
+                                let (ops, tail) = core::mem::take(&mut rest).split_at_mut(end);
+                                if !ops.is_empty() {
+                                    s.spi.try_exec(ops).map_err(SpiWithCsError::Spi)?;
+                                }
+                                if let Some((Operation::DelayUs(us), tail)) = tail.split_first_mut() {
+                                    s.flush_delay_us(*us)?;
+                                    rest = tail;
+                                }
+                            }
+
+                            return Ok(());
+                        }
+
+                        let mut first = true;
+                        for op in operations.iter_mut() {
+                            match op {
+                                Operation::DelayUs(us) => s.flush_delay_us(*us)?,
+                                Operation::Read(r) => {
+                                    for word in r.chunks_mut(1) {
+                                        s.word_gap(&mut first)?;
+                                        s.spi
+                                            .try_exec(&mut [Operation::Read(word)])
+                                            .map_err(SpiWithCsError::Spi)?;
+                                    }
+                                }
+                                Operation::Write(w) => {
+                                    for word in w.chunks(1) {
+                                        s.word_gap(&mut first)?;
+                                        s.spi
+                                            .try_exec(&mut [Operation::Write(word)])
+                                            .map_err(SpiWithCsError::Spi)?;
+                                    }
+                                }
+                                Operation::Transfer(r, w) => {
+                                    let mut w = w.chunks(1);
+                                    let mut r = r.chunks_mut(1);
+                                    loop {
+                                        let op = match (r.next(), w.next()) {
+                                            (Some(r), Some(w)) => Operation::Transfer(r, w),
+                                            (Some(r), None) => Operation::Read(r),
+                                            (None, Some(w)) => Operation::Write(w),
+                                            (None, None) => break,
+                                        };
+                                        s.word_gap(&mut first)?;
+                                        s.spi.try_exec(&mut [op]).map_err(SpiWithCsError::Spi)?;
+                                    }
+                                }
+                                Operation::TransferInPlace(t) => {
+                                    for word in t.chunks_mut(1) {
+                                        s.word_gap(&mut first)?;
+                                        s.spi
+                                            .try_exec(&mut [Operation::TransferInPlace(word)])
+                                            .map_err(SpiWithCsError::Spi)?;
+                                    }
+                                }
+                            }
+                        }
+
+                        Ok(())
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_spi_with_cs!(u8, u16, u32);
@@ -0,0 +1,231 @@
+//! Digital I/O compatibility
+
+// the v1 traits of 0.2 are deprecated, but still used by many drivers
+#![allow(deprecated)]
+
+use core::convert::Infallible;
+
+use embedded_hal_02::digital::{v1, v2 as v02};
+
+use super::{FromV02, FromV1, IntoV02, IntoV1};
+use crate::digital::{InputPin, OutputPin, StatefulOutputPin, ToggleableOutputPin};
+
+impl<T> InputPin for FromV02<T>
+where
+    T: v02::InputPin,
+{
+    type Error = T::Error;
+
+    fn try_is_high(&self) -> Result<bool, Self::Error> {
+        self.inner.is_high()
+    }
+
+    fn try_is_low(&self) -> Result<bool, Self::Error> {
+        self.inner.is_low()
+    }
+}
+
+impl<T> OutputPin for FromV02<T>
+where
+    T: v02::OutputPin,
+{
+    type Error = T::Error;
+
+    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+        self.inner.set_low()
+    }
+
+    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+        self.inner.set_high()
+    }
+}
+
+impl<T> StatefulOutputPin for FromV02<T>
+where
+    T: v02::StatefulOutputPin,
+{
+    fn try_is_set_high(&self) -> Result<bool, Self::Error> {
+        self.inner.is_set_high()
+    }
+
+    fn try_is_set_low(&self) -> Result<bool, Self::Error> {
+        self.inner.is_set_low()
+    }
+}
+
+impl<T> ToggleableOutputPin for FromV02<T>
+where
+    T: v02::ToggleableOutputPin,
+{
+    type Error = T::Error;
+
+    fn try_toggle(&mut self) -> Result<(), Self::Error> {
+        self.inner.toggle()
+    }
+}
+
+impl<T> v02::InputPin for IntoV02<T>
+where
+    T: InputPin,
+{
+    type Error = T::Error;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        self.inner.try_is_high()
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        self.inner.try_is_low()
+    }
+}
+
+impl<T> v02::OutputPin for IntoV02<T>
+where
+    T: OutputPin,
+{
+    type Error = T::Error;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        self.inner.try_set_low()
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        self.inner.try_set_high()
+    }
+}
+
+impl<T> v02::StatefulOutputPin for IntoV02<T>
+where
+    T: StatefulOutputPin,
+{
+    fn is_set_high(&self) -> Result<bool, Self::Error> {
+        self.inner.try_is_set_high()
+    }
+
+    fn is_set_low(&self) -> Result<bool, Self::Error> {
+        self.inner.try_is_set_low()
+    }
+}
+
+impl<T> v02::ToggleableOutputPin for IntoV02<T>
+where
+    T: ToggleableOutputPin,
+{
+    type Error = T::Error;
+
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        self.inner.try_toggle()
+    }
+}
+
+impl<T> InputPin for FromV1<T>
+where
+    T: v1::InputPin,
+{
+    type Error = Infallible;
+
+    fn try_is_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.inner.is_high())
+    }
+
+    fn try_is_low(&self) -> Result<bool, Self::Error> {
+        Ok(self.inner.is_low())
+    }
+}
+
+impl<T> OutputPin for FromV1<T>
+where
+    T: v1::OutputPin,
+{
+    type Error = Infallible;
+
+    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+        self.inner.set_low();
+
+        Ok(())
+    }
+
+    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+        self.inner.set_high();
+
+        Ok(())
+    }
+}
+
+impl<T> StatefulOutputPin for FromV1<T>
+where
+    T: v1::OutputPin + v1::StatefulOutputPin,
+{
+    fn try_is_set_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.inner.is_set_high())
+    }
+
+    fn try_is_set_low(&self) -> Result<bool, Self::Error> {
+        Ok(self.inner.is_set_low())
+    }
+}
+
+impl<T> ToggleableOutputPin for FromV1<T>
+where
+    T: v1::ToggleableOutputPin,
+{
+    type Error = Infallible;
+
+    fn try_toggle(&mut self) -> Result<(), Self::Error> {
+        self.inner.toggle();
+
+        Ok(())
+    }
+}
+
+impl<T> v1::InputPin for IntoV1<T>
+where
+    T: InputPin,
+    T::Error: core::fmt::Debug,
+{
+    fn is_high(&self) -> bool {
+        self.inner.try_is_high().unwrap()
+    }
+
+    fn is_low(&self) -> bool {
+        self.inner.try_is_low().unwrap()
+    }
+}
+
+impl<T> v1::OutputPin for IntoV1<T>
+where
+    T: OutputPin,
+    T::Error: core::fmt::Debug,
+{
+    fn set_low(&mut self) {
+        self.inner.try_set_low().unwrap()
+    }
+
+    fn set_high(&mut self) {
+        self.inner.try_set_high().unwrap()
+    }
+}
+
+impl<T> v1::StatefulOutputPin for IntoV1<T>
+where
+    T: StatefulOutputPin,
+    T::Error: core::fmt::Debug,
+{
+    fn is_set_high(&self) -> bool {
+        self.inner.try_is_set_high().unwrap()
+    }
+
+    fn is_set_low(&self) -> bool {
+        self.inner.try_is_set_low().unwrap()
+    }
+}
+
+impl<T> v1::ToggleableOutputPin for IntoV1<T>
+where
+    T: ToggleableOutputPin,
+    T::Error: core::fmt::Debug,
+{
+    fn toggle(&mut self) {
+        self.inner.try_toggle().unwrap()
+    }
+}
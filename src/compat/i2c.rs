@@ -0,0 +1,202 @@
+//! Blocking I2C compatibility
+
+use embedded_hal_02::blocking::i2c as v02;
+
+use super::{FromV02, IntoV02};
+use crate::blocking::i2c::{
+    AddressMode, Operation, Read, TransactionalIter, Write, WriteIter, WriteIterRead, WriteRead,
+};
+
+impl<A, T> Read<A> for FromV02<T>
+where
+    A: AddressMode + v02::AddressMode,
+    T: v02::Read<A>,
+{
+    type Error = T::Error;
+
+    fn try_read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.inner.read(address, buffer)
+    }
+}
+
+impl<A, T> Write<A> for FromV02<T>
+where
+    A: AddressMode + v02::AddressMode,
+    T: v02::Write<A>,
+{
+    type Error = T::Error;
+
+    fn try_write(&mut self, address: A, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.inner.write(address, bytes)
+    }
+}
+
+impl<A, T> WriteIter<A> for FromV02<T>
+where
+    A: AddressMode + v02::AddressMode,
+    T: v02::WriteIter<A>,
+{
+    type Error = T::Error;
+
+    fn try_write_iter<B>(&mut self, address: A, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        v02::WriteIter::write(&mut self.inner, address, bytes)
+    }
+}
+
+impl<A, T> WriteRead<A> for FromV02<T>
+where
+    A: AddressMode + v02::AddressMode,
+    T: v02::WriteRead<A>,
+{
+    type Error = T::Error;
+
+    fn try_write_read(
+        &mut self,
+        address: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.inner.write_read(address, bytes, buffer)
+    }
+}
+
+impl<A, T> WriteIterRead<A> for FromV02<T>
+where
+    A: AddressMode + v02::AddressMode,
+    T: v02::WriteIterRead<A>,
+{
+    type Error = T::Error;
+
+    fn try_write_iter_read<B>(
+        &mut self,
+        address: A,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        self.inner.write_iter_read(address, bytes, buffer)
+    }
+}
+
+impl<A, T> TransactionalIter<A> for FromV02<T>
+where
+    A: AddressMode + v02::AddressMode,
+    T: v02::TransactionalIter<A>,
+{
+    type Error = T::Error;
+
+    fn try_exec_iter<'a, O>(&mut self, address: A, operations: O) -> Result<(), Self::Error>
+    where
+        O: IntoIterator<Item = Operation<'a>>,
+    {
+        self.inner.exec_iter(
+            address,
+            operations.into_iter().map(|op| match op {
+                Operation::Read(buffer) => v02::Operation::Read(buffer),
+                Operation::Write(bytes) => v02::Operation::Write(bytes),
+            }),
+        )
+    }
+}
+
+impl<A, T> v02::Read<A> for IntoV02<T>
+where
+    A: AddressMode + v02::AddressMode,
+    T: Read<A>,
+{
+    type Error = T::Error;
+
+    fn read(&mut self, address: A, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.inner.try_read(address, buffer)
+    }
+}
+
+impl<A, T> v02::Write<A> for IntoV02<T>
+where
+    A: AddressMode + v02::AddressMode,
+    T: Write<A>,
+{
+    type Error = T::Error;
+
+    fn write(&mut self, address: A, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.inner.try_write(address, bytes)
+    }
+}
+
+impl<A, T> v02::WriteIter<A> for IntoV02<T>
+where
+    A: AddressMode + v02::AddressMode,
+    T: WriteIter<A>,
+{
+    type Error = T::Error;
+
+    fn write<B>(&mut self, address: A, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        self.inner.try_write_iter(address, bytes)
+    }
+}
+
+impl<A, T> v02::WriteRead<A> for IntoV02<T>
+where
+    A: AddressMode + v02::AddressMode,
+    T: WriteRead<A>,
+{
+    type Error = T::Error;
+
+    fn write_read(
+        &mut self,
+        address: A,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.inner.try_write_read(address, bytes, buffer)
+    }
+}
+
+impl<A, T> v02::WriteIterRead<A> for IntoV02<T>
+where
+    A: AddressMode + v02::AddressMode,
+    T: WriteIterRead<A>,
+{
+    type Error = T::Error;
+
+    fn write_iter_read<B>(
+        &mut self,
+        address: A,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        self.inner.try_write_iter_read(address, bytes, buffer)
+    }
+}
+
+impl<A, T> v02::TransactionalIter<A> for IntoV02<T>
+where
+    A: AddressMode + v02::AddressMode,
+    T: TransactionalIter<A>,
+{
+    type Error = T::Error;
+
+    fn exec_iter<'a, O>(&mut self, address: A, operations: O) -> Result<(), Self::Error>
+    where
+        O: IntoIterator<Item = v02::Operation<'a>>,
+    {
+        self.inner.try_exec_iter(
+            address,
+            operations.into_iter().map(|op| match op {
+                v02::Operation::Read(buffer) => Operation::Read(buffer),
+                v02::Operation::Write(bytes) => Operation::Write(bytes),
+            }),
+        )
+    }
+}
@@ -0,0 +1,191 @@
+//! Compatibility with `embedded-hal` 0.2
+//!
+//! Most drivers and HALs of the ecosystem are written against `embedded-hal` 0.2. The wrappers
+//! in this module allow mixing them with implementations and drivers written against this
+//! crate:
+//!
+//! - [`FromV02`] wraps an implementation of the 0.2 traits and implements the traits of this
+//!   crate on top of it.
+//! - [`IntoV02`] wraps an implementation of the traits of this crate and implements the 0.2
+//!   traits on top of it.
+//!
+//! The following traits are supported:
+//!
+//! - the blocking I2C traits, except `Transactional` as converting the slice of operations would
+//!   require allocating; `TransactionalIter` is supported.
+//! - the digital traits, bridged to the `digital::v2` traits of 0.2. Drivers using the
+//!   deprecated v1 traits can be given pins wrapped in [`IntoV1`], which panics on errors.
+//!
+//! Pins implementing the v1 traits implement the v2 traits as well, with `()` as error type.
+//! [`FromV1`] implements the digital traits of this crate directly on top of the v1 traits,
+//! with [`Infallible`](core::convert::Infallible) as error type instead.
+//!
+//! This module is only available when the `embedded-hal-02` feature is enabled.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::WriteRead;
+//! use embedded_hal::compat::FromV02;
+//! # use core::convert::Infallible;
+//! # struct I2c;
+//! # impl embedded_hal_02::blocking::i2c::WriteRead for I2c {
+//! #     type Error = Infallible;
+//! #     fn write_read(&mut self, _: u8, _: &[u8], _: &mut [u8]) -> Result<(), Self::Error> {
+//! #         Ok(())
+//! #     }
+//! # }
+//!
+//! // `I2c` implements `embedded_hal_02::blocking::i2c::WriteRead`
+//! let mut i2c = FromV02::new(I2c);
+//!
+//! let mut temperature = [0; 2];
+//! i2c.try_write_read(0x48, &[0x00], &mut temperature).unwrap();
+//! ```
+//!
+//! Passing an input pin of this crate to a driver using the `embedded-hal` 0.2 v1 traits:
+//!
+//! ```
+//! # #![allow(deprecated)]
+//! use embedded_hal::compat::IntoV1;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::digital::InputPin;
+//! # use embedded_hal_02::digital::v1;
+//! # struct Button;
+//! # impl InputPin for Button {
+//! #     type Error = Infallible;
+//! #     fn try_is_high(&self) -> Result<bool, Self::Error> { Ok(false) }
+//! #     fn try_is_low(&self) -> Result<bool, Self::Error> { Ok(true) }
+//! # }
+//! # let button = Button;
+//!
+//! fn is_pressed<P: v1::InputPin>(pin: &P) -> bool {
+//!     pin.is_low()
+//! }
+//!
+//! let button = IntoV1::new(button);
+//! assert!(is_pressed(&button));
+//! ```
+
+mod digital;
+mod i2c;
+
+/// Adapter implementing the traits of this crate for `embedded-hal` 0.2 implementations
+pub struct FromV02<T> {
+    inner: T,
+}
+
+impl<T> FromV02<T> {
+    /// Wraps the `embedded-hal` 0.2 implementation `inner`
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a mutable reference to the wrapped implementation
+    pub fn inner(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Destroys the adapter, returning the wrapped implementation
+    pub fn destroy(self) -> T {
+        self.inner
+    }
+}
+
+/// Adapter implementing the `embedded-hal` 0.2 traits for implementations of this crate
+pub struct IntoV02<T> {
+    inner: T,
+}
+
+impl<T> IntoV02<T> {
+    /// Wraps the implementation `inner`
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a mutable reference to the wrapped implementation
+    pub fn inner(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Destroys the adapter, returning the wrapped implementation
+    pub fn destroy(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> From<T> for FromV02<T> {
+    fn from(inner: T) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<T> From<T> for IntoV02<T> {
+    fn from(inner: T) -> Self {
+        Self::new(inner)
+    }
+}
+
+/// Adapter implementing the digital traits of this crate for `embedded-hal` 0.2 v1 pins
+///
+/// Pins implementing the deprecated `digital::v1` traits of 0.2 can't fail, but 0.2 implements
+/// the v2 traits for them with `()` as error type, which [`FromV02`] would forward. This adapter
+/// uses [`Infallible`](core::convert::Infallible) instead, so the errors can be converted
+/// into any other error type.
+pub struct FromV1<T> {
+    inner: T,
+}
+
+impl<T> FromV1<T> {
+    /// Wraps the `embedded-hal` 0.2 v1 pin `inner`
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a mutable reference to the wrapped pin
+    pub fn inner(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Destroys the adapter, returning the wrapped pin
+    pub fn destroy(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> From<T> for FromV1<T> {
+    fn from(inner: T) -> Self {
+        Self::new(inner)
+    }
+}
+
+/// Adapter implementing the `embedded-hal` 0.2 v1 digital traits for pins of this crate
+///
+/// The v1 traits can't report errors, so **the adapter panics if the pin returns one**. Unlike
+/// `digital::v1_compat` of 0.2, it supports `StatefulOutputPin` and `ToggleableOutputPin`.
+pub struct IntoV1<T> {
+    inner: T,
+}
+
+impl<T> IntoV1<T> {
+    /// Wraps the pin `inner`
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a mutable reference to the wrapped pin
+    pub fn inner(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Destroys the adapter, returning the wrapped pin
+    pub fn destroy(self) -> T {
+        self.inner
+    }
+}
+
+impl<T> From<T> for IntoV1<T> {
+    fn from(inner: T) -> Self {
+        Self::new(inner)
+    }
+}
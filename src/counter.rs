@@ -0,0 +1,82 @@
+//! Pulse counter
+
+/// Hardware pulse counter
+///
+/// Counts the edges of an input pin in hardware, for signals too fast to be counted in software
+/// like the outputs of flow meters, anemometers or tachometers. Which edges are counted is
+/// configured by the implementation.
+///
+/// # Examples
+///
+/// You can use this interface to measure the speed of a fan
+///
+/// ```
+/// extern crate embedded_hal as hal;
+/// #[macro_use(block)]
+/// extern crate nb;
+///
+/// use hal::prelude::*;
+///
+/// fn main() {
+///     let mut tachometer: Counter1 = {
+///         // ..
+/// #       Counter1
+///     };
+///     let mut timer: Timer6 = {
+///         // ..
+/// #       Timer6
+///     };
+///
+///     tachometer.try_clear().unwrap();
+///     tachometer.try_start().unwrap();
+///     timer.try_start(1.s()).unwrap();
+///     block!(timer.try_wait()).unwrap();
+///     tachometer.try_stop().unwrap();
+///
+///     // the fan outputs two pulses per revolution
+///     let rpm = u32::from(tachometer.try_count().unwrap()) * 60 / 2;
+///     println!("Speed: {} RPM", rpm);
+/// }
+///
+/// # use core::convert::Infallible;
+/// # struct Seconds(u32);
+/// # trait U32Ext { fn s(self) -> Seconds; }
+/// # impl U32Ext for u32 { fn s(self) -> Seconds { Seconds(self) } }
+/// # struct Counter1;
+/// # impl hal::counter::PulseCounter for Counter1 {
+/// #     type Error = Infallible;
+/// #     type Count = u16;
+/// #     fn try_start(&mut self) -> Result<(), Self::Error> { Ok(()) }
+/// #     fn try_stop(&mut self) -> Result<(), Self::Error> { Ok(()) }
+/// #     fn try_count(&self) -> Result<u16, Self::Error> { Ok(0) }
+/// #     fn try_clear(&mut self) -> Result<(), Self::Error> { Ok(()) }
+/// # }
+/// # struct Timer6;
+/// # impl hal::timer::CountDown for Timer6 {
+/// #     type Error = Infallible;
+/// #     type Time = Seconds;
+/// #     fn try_start<T>(&mut self, _: T) -> Result<(), Infallible> where T: Into<Seconds> { Ok(()) }
+/// #     fn try_wait(&mut self) -> ::nb::Result<(), Infallible> { Ok(()) }
+/// # }
+/// ```
+pub trait PulseCounter {
+    /// Enumeration of `PulseCounter` errors
+    type Error;
+
+    /// The type of the value returned by `count`
+    ///
+    /// The count wraps around once it reaches the maximum value of the type.
+    type Count;
+
+    /// Starts counting pulses, keeping the current count
+    fn try_start(&mut self) -> Result<(), Self::Error>;
+
+    /// Stops counting pulses, keeping the current count
+    fn try_stop(&mut self) -> Result<(), Self::Error>;
+
+    /// Returns the number of pulses counted since the last clear
+    fn try_count(&self) -> Result<Self::Count, Self::Error>;
+
+    /// Resets the count to zero
+    fn try_clear(&mut self) -> Result<(), Self::Error>;
+}
@@ -0,0 +1,145 @@
+//! Debouncing of input pins
+//!
+//! Mechanical switches and buttons bounce between levels for a few milliseconds when they
+//! change state. [`Debounced`] only reports a new level once the wrapped pin has held it for a
+//! configurable period, measured with a [`CountDown`].
+//!
+//! The level is sampled each time the pin is read, so the debounced level only changes while it
+//! is being polled.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::digital::debounced::Debounced;
+//! use embedded_hal::digital::InputPin;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::timer::CountDown;
+//! # struct Pin;
+//! # impl InputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_is_high(&self) -> Result<bool, Self::Error> { Ok(false) }
+//! #     fn try_is_low(&self) -> Result<bool, Self::Error> { Ok(true) }
+//! # }
+//! # #[derive(Clone)]
+//! # struct Millis(u32);
+//! # struct Timer;
+//! # impl CountDown for Timer {
+//! #     type Error = Infallible;
+//! #     type Time = Millis;
+//! #     fn try_start<T>(&mut self, _: T) -> Result<(), Self::Error> where T: Into<Millis> { Ok(()) }
+//! #     fn try_wait(&mut self) -> nb::Result<(), Infallible> { Ok(()) }
+//! # }
+//! # let (pin, timer) = (Pin, Timer);
+//!
+//! let button = Debounced::new(pin, timer, Millis(20)).unwrap();
+//!
+//! if button.try_is_low().unwrap() {
+//!     // pressed for at least 20 ms
+//! }
+//! ```
+
+use core::cell::{Cell, RefCell};
+
+use super::InputPin;
+use crate::timer::CountDown;
+
+wrapper_error! {
+    /// Debounced pin error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum DebouncedError<PinError, TimerError> {
+        /// Underlying pin error
+        Pin(PinError) => forward, "pin error",
+        /// Underlying timer error
+        Timer(TimerError) => Other, "timer error",
+    }
+
+    impl super::Error => super::ErrorKind where PinError: super::Error
+}
+
+/// Input pin only reporting levels that were stable for a period
+pub struct Debounced<P, Timer: CountDown> {
+    pin: P,
+    timer: RefCell<Timer>,
+    period: Timer::Time,
+    /// Debounced level, `true` for high
+    stable: Cell<bool>,
+    /// Level waiting for the period to elapse, if different from `stable`
+    pending: Cell<Option<bool>>,
+}
+
+impl<P, Timer> Debounced<P, Timer>
+where
+    P: InputPin,
+    Timer: CountDown,
+    Timer::Time: Clone,
+{
+    /// Wraps `pin`, starting from its current level
+    ///
+    /// Changes of level are reported once they have lasted for `period`.
+    pub fn new<T>(
+        pin: P,
+        timer: Timer,
+        period: T,
+    ) -> Result<Self, DebouncedError<P::Error, Timer::Error>>
+    where
+        T: Into<Timer::Time>,
+    {
+        let stable = pin.try_is_high().map_err(DebouncedError::Pin)?;
+
+        Ok(Self {
+            pin,
+            timer: RefCell::new(timer),
+            period: period.into(),
+            stable: Cell::new(stable),
+            pending: Cell::new(None),
+        })
+    }
+
+    /// Destroys the wrapper, returning the pin and timer
+    pub fn destroy(self) -> (P, Timer) {
+        (self.pin, self.timer.into_inner())
+    }
+
+    /// Samples the pin, returning the debounced level
+    fn update(&self) -> Result<bool, DebouncedError<P::Error, Timer::Error>> {
+        let level = self.pin.try_is_high().map_err(DebouncedError::Pin)?;
+        let mut timer = self.timer.borrow_mut();
+
+        if level == self.stable.get() {
+            self.pending.set(None);
+        } else if self.pending.get() != Some(level) {
+            timer
+                .try_start(self.period.clone())
+                .map_err(DebouncedError::Timer)?;
+            self.pending.set(Some(level));
+        } else {
+            match timer.try_wait() {
+                Ok(()) => {
+                    self.stable.set(level);
+                    self.pending.set(None);
+                }
+                Err(nb::Error::WouldBlock) => {}
+                Err(nb::Error::Other(e)) => return Err(DebouncedError::Timer(e)),
+            }
+        }
+
+        Ok(self.stable.get())
+    }
+}
+
+impl<P, Timer> InputPin for Debounced<P, Timer>
+where
+    P: InputPin,
+    Timer: CountDown,
+    Timer::Time: Clone,
+{
+    type Error = DebouncedError<P::Error, Timer::Error>;
+
+    fn try_is_high(&self) -> Result<bool, Self::Error> {
+        self.update()
+    }
+
+    fn try_is_low(&self) -> Result<bool, Self::Error> {
+        self.update().map(|high| !high)
+    }
+}
@@ -0,0 +1,75 @@
+//! Placeholder for unconnected pins
+//!
+//! Drivers often have optional lines, like a reset or chip select pin that can be tied to a
+//! fixed level on the board. Rather than taking an `Option` of a pin, they can keep taking a pin
+//! and let users pass a [`DummyPin`] for the unconnected lines.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::digital::dummy::DummyPin;
+//! use embedded_hal::digital::{InputPin, OutputPin};
+//!
+//! struct Display<Reset, Busy> {
+//!     reset: Reset,
+//!     busy: Busy,
+//! }
+//!
+//! // the reset line is tied high and the busy line isn't connected
+//! let mut display = Display {
+//!     reset: DummyPin::new_high(),
+//!     busy: DummyPin::new_low(),
+//! };
+//!
+//! display.reset.try_set_low().unwrap();
+//! assert!(display.busy.try_is_low().unwrap());
+//! ```
+
+use core::convert::Infallible;
+
+use super::{InputPin, OutputPin};
+
+/// Pin ignoring the levels it is driven to and reading a fixed level
+///
+/// The pin reads high if `HIGH` is `true` and low otherwise. It is zero sized and none of its
+/// operations fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DummyPin<const HIGH: bool>;
+
+impl DummyPin<false> {
+    /// Creates a new dummy pin reading low
+    pub const fn new_low() -> Self {
+        DummyPin
+    }
+}
+
+impl DummyPin<true> {
+    /// Creates a new dummy pin reading high
+    pub const fn new_high() -> Self {
+        DummyPin
+    }
+}
+
+impl<const HIGH: bool> OutputPin for DummyPin<HIGH> {
+    type Error = Infallible;
+
+    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<const HIGH: bool> InputPin for DummyPin<HIGH> {
+    type Error = Infallible;
+
+    fn try_is_high(&self) -> Result<bool, Self::Error> {
+        Ok(HIGH)
+    }
+
+    fn try_is_low(&self) -> Result<bool, Self::Error> {
+        Ok(!HIGH)
+    }
+}
@@ -0,0 +1,177 @@
+//! GPIO expanders
+//!
+//! GPIO expanders like the MCP23017 or PCA9555 provide additional pins over I2C or SPI. Their
+//! drivers implement [`PortExpander`] for the port registers, and a [`SharedExpander`] hands out
+//! an [`ExpanderPin`] for each pin, implementing the digital traits of this crate so the pins can
+//! be used by any driver. Each pin operation reads or modifies the port registers of the expander.
+//!
+//! Configuring the direction of the pins is left to the expander driver. The pins borrow the
+//! expander through a `RefCell`, so they can only be used from a single execution context.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::digital::expander::{PortExpander, SharedExpander};
+//! use embedded_hal::digital::{InputPin, OutputPin};
+//! # use core::convert::Infallible;
+//! # struct Mcp23017 { outputs: u16 }
+//! # impl PortExpander for Mcp23017 {
+//! #     type Error = Infallible;
+//! #     type Word = u16;
+//! #     fn try_read(&mut self) -> Result<u16, Self::Error> { Ok(self.outputs) }
+//! #     fn try_read_output(&mut self) -> Result<u16, Self::Error> { Ok(self.outputs) }
+//! #     fn try_modify(&mut self, clear: u16, set: u16) -> Result<(), Self::Error> {
+//! #         self.outputs = self.outputs & !clear | set;
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let mcp23017 = Mcp23017 { outputs: 0 };
+//!
+//! let expander = SharedExpander::new(mcp23017);
+//!
+//! let mut led = expander.pin(0);
+//! let button = expander.pin(8);
+//!
+//! led.try_set_high().unwrap();
+//! if button.try_is_low().unwrap() {
+//!     // pressed
+//! }
+//! ```
+
+use core::cell::RefCell;
+use core::ops::{BitAnd, Shl};
+
+use super::{InputPin, OutputPin, StatefulOutputPin};
+
+/// Port registers of a GPIO expander
+///
+/// Bit `n` of a word is the `n`-th pin of the expander, a set bit meaning high.
+pub trait PortExpander {
+    /// Error type
+    type Error;
+
+    /// Word with one bit per pin of the expander
+    type Word;
+
+    /// Reads the input levels of the pins
+    fn try_read(&mut self) -> Result<Self::Word, Self::Error>;
+
+    /// Reads the levels the output pins are driven to
+    fn try_read_output(&mut self) -> Result<Self::Word, Self::Error>;
+
+    /// Drives the output pins of the bits set in `clear` low, then those set in `set` high
+    ///
+    /// The other output pins keep their levels.
+    fn try_modify(&mut self, clear: Self::Word, set: Self::Word) -> Result<(), Self::Error>;
+}
+
+/// GPIO expander shared between its pins
+pub struct SharedExpander<X> {
+    expander: RefCell<X>,
+}
+
+impl<X> SharedExpander<X>
+where
+    X: PortExpander,
+{
+    /// Creates a new shared expander from the provided expander driver
+    pub fn new(expander: X) -> Self {
+        Self {
+            expander: RefCell::new(expander),
+        }
+    }
+
+    /// Creates a new handle to the pin `bit` of the expander
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit` is beyond the width of the expander.
+    pub fn pin(&self, bit: u8) -> ExpanderPin<'_, X>
+    where
+        X::Word: From<u8> + Shl<u8, Output = X::Word>,
+    {
+        assert!(
+            usize::from(bit) < core::mem::size_of::<X::Word>() * 8,
+            "pin {} beyond the width of the expander",
+            bit
+        );
+
+        ExpanderPin {
+            expander: &self.expander,
+            mask: X::Word::from(1) << bit,
+        }
+    }
+
+    /// Destroys the shared expander, returning the expander driver
+    pub fn destroy(self) -> X {
+        self.expander.into_inner()
+    }
+}
+
+/// Single pin of a [`SharedExpander`]
+pub struct ExpanderPin<'a, X: PortExpander> {
+    expander: &'a RefCell<X>,
+    mask: X::Word,
+}
+
+impl<'a, X> ExpanderPin<'a, X>
+where
+    X: PortExpander,
+    X::Word: Copy + PartialEq + From<u8> + BitAnd<Output = X::Word>,
+{
+    fn is_set(&self, word: X::Word) -> bool {
+        word & self.mask != X::Word::from(0)
+    }
+}
+
+impl<'a, X> OutputPin for ExpanderPin<'a, X>
+where
+    X: PortExpander,
+    X::Word: Copy + From<u8>,
+{
+    type Error = X::Error;
+
+    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+        self.expander
+            .borrow_mut()
+            .try_modify(self.mask, X::Word::from(0))
+    }
+
+    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+        self.expander
+            .borrow_mut()
+            .try_modify(X::Word::from(0), self.mask)
+    }
+}
+
+impl<'a, X> StatefulOutputPin for ExpanderPin<'a, X>
+where
+    X: PortExpander,
+    X::Word: Copy + PartialEq + From<u8> + BitAnd<Output = X::Word>,
+{
+    fn try_is_set_high(&self) -> Result<bool, Self::Error> {
+        let word = self.expander.borrow_mut().try_read_output()?;
+        Ok(self.is_set(word))
+    }
+
+    fn try_is_set_low(&self) -> Result<bool, Self::Error> {
+        self.try_is_set_high().map(|high| !high)
+    }
+}
+
+impl<'a, X> InputPin for ExpanderPin<'a, X>
+where
+    X: PortExpander,
+    X::Word: Copy + PartialEq + From<u8> + BitAnd<Output = X::Word>,
+{
+    type Error = X::Error;
+
+    fn try_is_high(&self) -> Result<bool, Self::Error> {
+        let word = self.expander.borrow_mut().try_read()?;
+        Ok(self.is_set(word))
+    }
+
+    fn try_is_low(&self) -> Result<bool, Self::Error> {
+        self.try_is_high().map(|high| !high)
+    }
+}
@@ -0,0 +1,95 @@
+//! Ports made of individual pins
+//!
+//! [`PinGroup`] implements [`OutputPort`] and [`InputPort`] over an array of pins, for parallel
+//! interfaces on hardware without a suitable port peripheral. The pins are accessed one after
+//! the other, so unlike a native port **the access is not atomic**: while writing, the lines
+//! briefly show a mix of the old and new values. Interfaces with a separate strobe or enable
+//! line, like HD44780 displays, are not affected.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::digital::group::PinGroup;
+//! use embedded_hal::digital::{OutputPin, OutputPort};
+//! # use core::convert::Infallible;
+//! # struct Pin;
+//! # impl OutputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (d4, d5, d6, d7) = (Pin, Pin, Pin, Pin);
+//!
+//! let mut data = PinGroup::new([d4, d5, d6, d7]);
+//! data.try_write(0b1010u8).unwrap();
+//! ```
+
+use super::{InputPin, InputPort, OutputPin, OutputPort};
+
+/// Port made of individual pins
+///
+/// The first pin of the array is bit 0 of the port.
+pub struct PinGroup<T> {
+    pins: T,
+}
+
+impl<T> PinGroup<T> {
+    /// Groups the `pins` into a port
+    pub fn new(pins: T) -> Self {
+        Self { pins }
+    }
+
+    /// Destroys the port, returning the pins
+    pub fn destroy(self) -> T {
+        self.pins
+    }
+}
+
+macro_rules! impl_port {
+    ($($W:ty),*) => {
+        $(
+            impl<P, const N: usize> OutputPort<$W> for PinGroup<[P; N]>
+            where
+                P: OutputPin,
+            {
+                type Error = P::Error;
+
+                /// Drives the pins in order, driving those beyond the width of the word low
+                fn try_write(&mut self, word: $W) -> Result<(), Self::Error> {
+                    for (i, pin) in self.pins.iter_mut().enumerate() {
+                        let bit = u128::from(word).checked_shr(i as u32).unwrap_or(0) & 1;
+                        if bit == 1 {
+                            pin.try_set_high()?;
+                        } else {
+                            pin.try_set_low()?;
+                        }
+                    }
+
+                    Ok(())
+                }
+            }
+
+            impl<P, const N: usize> InputPort<$W> for PinGroup<[P; N]>
+            where
+                P: InputPin,
+            {
+                type Error = P::Error;
+
+                /// Reads the pins in order, ignoring those beyond the width of the word
+                fn try_read(&self) -> Result<$W, Self::Error> {
+                    let bits = core::mem::size_of::<$W>() * 8;
+                    let mut word = 0;
+                    for (i, pin) in self.pins.iter().take(bits).enumerate() {
+                        if pin.try_is_high()? {
+                            word |= 1 << i;
+                        }
+                    }
+
+                    Ok(word)
+                }
+            }
+        )*
+    };
+}
+
+impl_port!(u8, u16, u32);
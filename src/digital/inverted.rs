@@ -0,0 +1,97 @@
+//! Active low pins
+//!
+//! Boards often wire signals active low, e.g. an LED between the pin and the supply or a button
+//! pulling the pin to ground. [`InvertedPin`] inverts the logic of a pin, so drivers can be
+//! written in terms of the asserted state of a signal regardless of the wiring.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::digital::inverted::InvertedPin;
+//! use embedded_hal::digital::{InputPin, OutputPin};
+//! # use core::convert::Infallible;
+//! # struct Pin;
+//! # impl OutputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl InputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_is_high(&self) -> Result<bool, Self::Error> { Ok(false) }
+//! #     fn try_is_low(&self) -> Result<bool, Self::Error> { Ok(true) }
+//! # }
+//! # let (led_pin, button_pin) = (Pin, Pin);
+//!
+//! // LED lit when the pin is low
+//! let mut led = InvertedPin::new(led_pin);
+//! led.try_set_high().unwrap();
+//!
+//! // button pulling the pin low when pressed
+//! let button = InvertedPin::new(button_pin);
+//! assert!(button.try_is_high().unwrap());
+//! ```
+
+use super::{InputPin, OutputPin, StatefulOutputPin, ToggleableOutputPin};
+
+/// Pin with inverted logic
+///
+/// Driving the pin high drives the wrapped pin low and vice versa, and reading it returns the
+/// opposite of the level of the wrapped pin.
+pub struct InvertedPin<P> {
+    pin: P,
+}
+
+impl<P> InvertedPin<P> {
+    /// Wraps `pin`, inverting its logic
+    pub fn new(pin: P) -> Self {
+        Self { pin }
+    }
+
+    /// Destroys the wrapper, returning the pin
+    pub fn destroy(self) -> P {
+        self.pin
+    }
+}
+
+impl<P: OutputPin> OutputPin for InvertedPin<P> {
+    type Error = P::Error;
+
+    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+        self.pin.try_set_high()
+    }
+
+    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+        self.pin.try_set_low()
+    }
+}
+
+impl<P: StatefulOutputPin> StatefulOutputPin for InvertedPin<P> {
+    fn try_is_set_high(&self) -> Result<bool, Self::Error> {
+        self.pin.try_is_set_low()
+    }
+
+    fn try_is_set_low(&self) -> Result<bool, Self::Error> {
+        self.pin.try_is_set_high()
+    }
+}
+
+impl<P: ToggleableOutputPin> ToggleableOutputPin for InvertedPin<P> {
+    type Error = P::Error;
+
+    fn try_toggle(&mut self) -> Result<(), Self::Error> {
+        self.pin.try_toggle()
+    }
+}
+
+impl<P: InputPin> InputPin for InvertedPin<P> {
+    type Error = P::Error;
+
+    fn try_is_high(&self) -> Result<bool, Self::Error> {
+        self.pin.try_is_low()
+    }
+
+    fn try_is_low(&self) -> Result<bool, Self::Error> {
+        self.pin.try_is_high()
+    }
+}
@@ -0,0 +1,173 @@
+//! Key matrix scanning
+//!
+//! Keypads wire their keys in a matrix: pressing a key connects its row to its column. A
+//! [`Keypad`] drives the rows one after the other and reads which columns follow, returning the
+//! pressed keys as a bitmap.
+//!
+//! The rows are driven low one at a time, with the other rows driven high, and the columns are
+//! expected to be pulled up, so a pressed key reads low. Pressing several keys of a column then
+//! shorts a low row to a high one; if the rows can be released to high impedance, implementing
+//! [`TriStatePin`], [`try_scan_floating`](Keypad::try_scan_floating) leaves the other rows
+//! floating instead. Either way, pressing three keys at the corners of a rectangle also reads
+//! the fourth as pressed, unless the keys have diodes.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::digital::matrix::Keypad;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::delay::DelayUs;
+//! # use embedded_hal::digital::{InputPin, OutputPin};
+//! # struct Row;
+//! # impl OutputPin for Row {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # struct Col;
+//! # impl InputPin for Col {
+//! #     type Error = Infallible;
+//! #     fn try_is_high(&self) -> Result<bool, Self::Error> { Ok(true) }
+//! #     fn try_is_low(&self) -> Result<bool, Self::Error> { Ok(false) }
+//! # }
+//! # struct Delay;
+//! # impl DelayUs<u32> for Delay {
+//! #     type Error = Infallible;
+//! #     fn try_delay_us(&mut self, _: u32) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (r0, r1, r2, r3) = (Row, Row, Row, Row);
+//! # let (c0, c1, c2) = (Col, Col, Col);
+//! # let delay = Delay;
+//!
+//! const KEYS: [char; 12] = ['1', '2', '3', '4', '5', '6', '7', '8', '9', '*', '0', '#'];
+//!
+//! // 4x3 phone keypad, letting the lines settle for 10 us
+//! let mut keypad = Keypad::new([r0, r1, r2, r3], [c0, c1, c2], delay, 10);
+//!
+//! let keys = keypad.try_scan().unwrap();
+//! for (i, key) in KEYS.iter().enumerate() {
+//!     if keys & 1 << i != 0 {
+//!         // `key` is pressed
+//!     }
+//! }
+//! ```
+
+use super::{InputPin, OutputPin, TriStatePin};
+use crate::blocking::delay::DelayUs;
+
+wrapper_error! {
+    /// Key matrix scanning error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum KeypadError<PinError, DelayError> {
+        /// Underlying pin error
+        Pin(PinError) => forward, "pin error",
+        /// Underlying delay error
+        Delay(DelayError) => Other, "delay error",
+    }
+
+    impl super::Error => super::ErrorKind where PinError: super::Error
+}
+
+/// Keypad made of a matrix of row and column pins
+pub struct Keypad<Rows, Cols, Delay> {
+    rows: Rows,
+    cols: Cols,
+    delay: Delay,
+    settle_us: u32,
+}
+
+impl<R, C, Delay, E, const ROWS: usize, const COLS: usize> Keypad<[R; ROWS], [C; COLS], Delay>
+where
+    R: OutputPin<Error = E>,
+    C: InputPin<Error = E>,
+    Delay: DelayUs<u32>,
+{
+    /// Creates a new keypad
+    ///
+    /// `settle_us` is the time in microseconds to wait after driving a row before reading the
+    /// columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keypad has more than 64 keys.
+    pub fn new(rows: [R; ROWS], cols: [C; COLS], delay: Delay, settle_us: u32) -> Self {
+        assert!(ROWS * COLS <= 64, "keypads are limited to 64 keys");
+
+        Self {
+            rows,
+            cols,
+            delay,
+            settle_us,
+        }
+    }
+
+    /// Destroys the keypad, returning the pins and delay
+    pub fn destroy(self) -> ([R; ROWS], [C; COLS], Delay) {
+        (self.rows, self.cols, self.delay)
+    }
+
+    /// Returns the pressed keys
+    ///
+    /// The key at row `r` and column `c` is bit `r * COLS + c` of the returned bitmap.
+    ///
+    /// The rows that aren't scanned are driven high. The driven row is released even if reading
+    /// the columns fails, but the error of the read is returned first.
+    pub fn try_scan(&mut self) -> Result<u64, KeypadError<E, Delay::Error>> {
+        self.scan(R::try_set_high)
+    }
+
+    /// Scans the rows, releasing the rows that aren't scanned with `release`
+    fn scan(
+        &mut self,
+        release: fn(&mut R) -> Result<(), E>,
+    ) -> Result<u64, KeypadError<E, Delay::Error>> {
+        for row in self.rows.iter_mut() {
+            release(row).map_err(KeypadError::Pin)?;
+        }
+
+        let mut keys = 0;
+        for (r, row) in self.rows.iter_mut().enumerate() {
+            row.try_set_low().map_err(KeypadError::Pin)?;
+            let read = Self::read_row(&mut self.delay, self.settle_us, &self.cols);
+            let release = release(row).map_err(KeypadError::Pin);
+
+            keys |= read? << (r * COLS);
+            release?;
+        }
+
+        Ok(keys)
+    }
+
+    /// Returns the columns of the driven row with a pressed key, as a bitmap
+    fn read_row(
+        delay: &mut Delay,
+        settle_us: u32,
+        cols: &[C; COLS],
+    ) -> Result<u64, KeypadError<E, Delay::Error>> {
+        delay.try_delay_us(settle_us).map_err(KeypadError::Delay)?;
+
+        let mut keys = 0;
+        for (c, col) in cols.iter().enumerate() {
+            if col.try_is_low().map_err(KeypadError::Pin)? {
+                keys |= 1 << c;
+            }
+        }
+
+        Ok(keys)
+    }
+}
+
+impl<R, C, Delay, E, const ROWS: usize, const COLS: usize> Keypad<[R; ROWS], [C; COLS], Delay>
+where
+    R: TriStatePin<Error = E>,
+    C: InputPin<Error = E>,
+    Delay: DelayUs<u32>,
+{
+    /// Returns the pressed keys, leaving the rows that aren't scanned floating
+    ///
+    /// This is [`try_scan`](Keypad::try_scan) for rows that can be released to high impedance,
+    /// so pressing several keys of a column doesn't short the rows.
+    pub fn try_scan_floating(&mut self) -> Result<u64, KeypadError<E, Delay::Error>> {
+        self.scan(R::try_set_floating)
+    }
+}
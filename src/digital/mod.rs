@@ -0,0 +1,705 @@
+//! Digital I/O
+
+pub mod debounced;
+pub mod dummy;
+pub mod expander;
+pub mod group;
+pub mod inverted;
+pub mod matrix;
+pub mod open_drain;
+pub mod pulse;
+#[cfg(feature = "critical-section")]
+pub mod shared;
+pub mod soft_pwm;
+
+use core::{convert::From, ops::Not};
+
+/// Digital output pin state
+///
+/// Conversion from and to `bool` and logical negation are also implemented
+/// for this type.
+/// ```rust
+/// # use embedded_hal::digital::PinState;
+/// let state = PinState::from(false);
+/// assert_eq!(state, PinState::Low);
+/// assert_eq!(!state, PinState::High);
+/// assert!(bool::from(!state));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PinState {
+    /// Low pin state
+    Low,
+    /// High pin state
+    High,
+}
+
+impl From<bool> for PinState {
+    fn from(value: bool) -> Self {
+        match value {
+            false => PinState::Low,
+            true => PinState::High,
+        }
+    }
+}
+
+impl From<PinState> for bool {
+    fn from(value: PinState) -> bool {
+        match value {
+            PinState::Low => false,
+            PinState::High => true,
+        }
+    }
+}
+
+impl Not for PinState {
+    type Output = PinState;
+
+    fn not(self) -> Self::Output {
+        match self {
+            PinState::High => PinState::Low,
+            PinState::Low => PinState::High,
+        }
+    }
+}
+
+/// Digital I/O error
+///
+/// Implemented by the error types of pins so generic code can classify failures without knowing
+/// the concrete error type, e.g. to tell a disconnected I/O expander apart from other errors:
+///
+/// ```
+/// use embedded_hal::digital::{Error, ErrorKind, OutputPin};
+///
+/// /// Turns the LED off, ignoring that the expander driving it was unplugged
+/// fn led_off<P>(led: &mut P) -> Result<(), P::Error>
+/// where
+///     P: OutputPin,
+///     P::Error: Error,
+/// {
+///     match led.try_set_low() {
+///         Err(e) if e.kind() == ErrorKind::Disconnected => Ok(()),
+///         result => result,
+///     }
+/// }
+/// ```
+pub trait Error: core::fmt::Debug {
+    /// Converts the error to a generic digital I/O error kind
+    ///
+    /// Implementations should return `ErrorKind::Other` for errors that don't match any of the
+    /// generic kinds.
+    fn kind(&self) -> ErrorKind;
+}
+
+impl Error for core::convert::Infallible {
+    fn kind(&self) -> ErrorKind {
+        match *self {}
+    }
+}
+
+/// Generic digital I/O error kind
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The device providing the pin, e.g. an I/O expander, can't be reached
+    Disconnected,
+    /// A different error occurred
+    Other,
+}
+
+impl core::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ErrorKind::Disconnected => write!(f, "the device providing the pin can't be reached"),
+            ErrorKind::Other => write!(f, "an unspecified error occurred"),
+        }
+    }
+}
+
+/// Single digital push-pull output pin
+pub trait OutputPin {
+    /// Error type
+    type Error;
+
+    /// Drives the pin low
+    ///
+    /// *NOTE* the actual electrical state of the pin may not actually be low, e.g. due to external
+    /// electrical sources
+    fn try_set_low(&mut self) -> Result<(), Self::Error>;
+
+    /// Drives the pin high
+    ///
+    /// *NOTE* the actual electrical state of the pin may not actually be high, e.g. due to external
+    /// electrical sources
+    fn try_set_high(&mut self) -> Result<(), Self::Error>;
+
+    /// Drives the pin high or low depending on the provided value
+    ///
+    /// *NOTE* the actual electrical state of the pin may not actually be high or low, e.g. due to external
+    /// electrical sources
+    ///
+    /// ```
+    /// use embedded_hal::digital::{OutputPin, PinState};
+    ///
+    /// /// Shows the least significant bit of `value` on the LED
+    /// fn show_bit<P: OutputPin>(led: &mut P, value: u8) -> Result<(), P::Error> {
+    ///     led.try_set_state(PinState::from(value & 1 != 0))
+    /// }
+    /// ```
+    fn try_set_state(&mut self, state: PinState) -> Result<(), Self::Error> {
+        match state {
+            PinState::Low => self.try_set_low(),
+            PinState::High => self.try_set_high(),
+        }
+    }
+}
+
+impl<T: OutputPin + ?Sized> OutputPin for &mut T {
+    type Error = T::Error;
+
+    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+        T::try_set_low(self)
+    }
+
+    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+        T::try_set_high(self)
+    }
+
+    fn try_set_state(&mut self, state: PinState) -> Result<(), Self::Error> {
+        T::try_set_state(self, state)
+    }
+}
+
+/// Push-pull output pin that can read its output state
+pub trait StatefulOutputPin: OutputPin {
+    /// Is the pin in drive high mode?
+    ///
+    /// *NOTE* this does *not* read the electrical state of the pin
+    fn try_is_set_high(&self) -> Result<bool, Self::Error>;
+
+    /// Is the pin in drive low mode?
+    ///
+    /// *NOTE* this does *not* read the electrical state of the pin
+    fn try_is_set_low(&self) -> Result<bool, Self::Error>;
+}
+
+impl<T: StatefulOutputPin + ?Sized> StatefulOutputPin for &mut T {
+    fn try_is_set_high(&self) -> Result<bool, Self::Error> {
+        T::try_is_set_high(self)
+    }
+
+    fn try_is_set_low(&self) -> Result<bool, Self::Error> {
+        T::try_is_set_low(self)
+    }
+}
+
+/// Output pin that can be toggled
+///
+/// See [toggleable](toggleable) to use a software implementation if
+/// both [OutputPin](trait.OutputPin.html) and
+/// [StatefulOutputPin](trait.StatefulOutputPin.html) are
+/// implemented. Otherwise, implement this using hardware mechanisms.
+///
+/// Like the other digital traits toggling is fallible; the software
+/// implementation reports the errors of reading the output state and of
+/// driving the pin.
+///
+/// Unlike the other pin traits, this trait isn't implemented for `&mut P`,
+/// as that would conflict with the software implementation.
+pub trait ToggleableOutputPin {
+    /// Error type
+    type Error;
+
+    /// Toggle pin output.
+    fn try_toggle(&mut self) -> Result<(), Self::Error>;
+}
+
+/// If you can read **and** write the output state, a pin is
+/// toggleable by software.
+///
+/// ```
+/// use embedded_hal::digital::{OutputPin, StatefulOutputPin, ToggleableOutputPin};
+/// use embedded_hal::digital::toggleable;
+/// use core::convert::Infallible;
+///
+/// /// A virtual output pin that exists purely in software
+/// struct MyPin {
+///     state: bool
+/// }
+///
+/// impl OutputPin for MyPin {
+///    type Error = Infallible;
+///
+///    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+///        self.state = false;
+///        Ok(())
+///    }
+///    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+///        self.state = true;
+///        Ok(())
+///    }
+/// }
+///
+/// impl StatefulOutputPin for MyPin {
+///    fn try_is_set_low(&self) -> Result<bool, Self::Error> {
+///        Ok(!self.state)
+///    }
+///    fn try_is_set_high(&self) -> Result<bool, Self::Error> {
+///        Ok(self.state)
+///    }
+/// }
+///
+/// /// Opt-in to the software implementation.
+/// impl toggleable::Default for MyPin {}
+///
+/// let mut pin = MyPin { state: false };
+/// pin.try_toggle().unwrap();
+/// assert!(pin.try_is_set_high().unwrap());
+/// pin.try_toggle().unwrap();
+/// assert!(pin.try_is_set_low().unwrap());
+/// ```
+pub mod toggleable {
+    use super::{OutputPin, StatefulOutputPin, ToggleableOutputPin};
+
+    /// Software-driven `try_toggle()` implementation.
+    ///
+    /// The output state is read with `try_is_set_low` and the pin is then
+    /// driven to the opposite state, so the toggle is not atomic.
+    pub trait Default: OutputPin + StatefulOutputPin {}
+
+    impl<P> ToggleableOutputPin for P
+    where
+        P: Default,
+    {
+        type Error = P::Error;
+
+        /// Toggle pin output
+        fn try_toggle(&mut self) -> Result<(), Self::Error> {
+            if self.try_is_set_low()? {
+                self.try_set_high()
+            } else {
+                self.try_set_low()
+            }
+        }
+    }
+}
+
+/// Single digital input pin
+pub trait InputPin {
+    /// Error type
+    type Error;
+
+    /// Is the input pin high?
+    fn try_is_high(&self) -> Result<bool, Self::Error>;
+
+    /// Is the input pin low?
+    fn try_is_low(&self) -> Result<bool, Self::Error>;
+}
+
+impl<T: InputPin + ?Sized> InputPin for &T {
+    type Error = T::Error;
+
+    fn try_is_high(&self) -> Result<bool, Self::Error> {
+        T::try_is_high(self)
+    }
+
+    fn try_is_low(&self) -> Result<bool, Self::Error> {
+        T::try_is_low(self)
+    }
+}
+
+impl<T: InputPin + ?Sized> InputPin for &mut T {
+    type Error = T::Error;
+
+    fn try_is_high(&self) -> Result<bool, Self::Error> {
+        T::try_is_high(self)
+    }
+
+    fn try_is_low(&self) -> Result<bool, Self::Error> {
+        T::try_is_low(self)
+    }
+}
+
+/// Single pin that can switch between input and output mode at runtime
+///
+/// The pin is consumed and returned as a different type in each mode, so the compiler checks
+/// that it is only read in input mode and only driven in output mode. `TInput` and `TOutput`
+/// implement this trait as well, allowing further mode switches.
+///
+/// This is needed for protocols where the same line is driven by both sides in turn, e.g. the
+/// data line of a DHT22 sensor:
+///
+/// ```
+/// use embedded_hal::blocking::delay::DelayUs;
+/// use embedded_hal::digital::{InputPin, IoPin, OutputPin, PinState};
+///
+/// /// Sends the start signal, then hands the line over to the sensor
+/// fn start<I, O, E, D>(pin: O, delay: &mut D) -> Result<I, E>
+/// where
+///     I: InputPin<Error = E> + IoPin<I, O, Error = E>,
+///     O: OutputPin<Error = E> + IoPin<I, O, Error = E>,
+///     D: DelayUs<u32, Error = E>,
+/// {
+///     let mut pin = pin.try_into_output(PinState::Low)?;
+///     delay.try_delay_us(1_000)?;
+///     pin.try_set_high()?;
+///     pin.try_into_input()
+/// }
+/// ```
+pub trait IoPin<TInput, TOutput>
+where
+    TInput: InputPin + IoPin<TInput, TOutput>,
+    TOutput: OutputPin + IoPin<TInput, TOutput>,
+{
+    /// Error type
+    type Error;
+
+    /// Converts the pin to input mode
+    ///
+    /// If the pin is already in input mode, this succeeds.
+    fn try_into_input(self) -> Result<TInput, Self::Error>;
+
+    /// Converts the pin to output mode, driving it to `state`
+    ///
+    /// If the pin is already in output mode, this succeeds and drives the pin to `state`.
+    fn try_into_output(self, state: PinState) -> Result<TOutput, Self::Error>;
+}
+
+/// Pin interrupt trigger condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trigger {
+    /// Low to high transition
+    RisingEdge,
+    /// High to low transition
+    FallingEdge,
+    /// Any transition
+    BothEdges,
+    /// Pin held high
+    HighLevel,
+    /// Pin held low
+    LowLevel,
+}
+
+/// Input pin able to raise an interrupt
+///
+/// Drivers for devices with an interrupt line use this to configure and acknowledge the
+/// interrupt; executing the interrupt handler is left to the application.
+///
+/// ```
+/// use embedded_hal::digital::{InterruptPin, Trigger};
+///
+/// /// Called from the interrupt handler of the accelerometer's data ready line
+/// fn on_data_ready<P: InterruptPin>(irq: &mut P) -> Result<bool, P::Error> {
+///     if !irq.try_is_interrupt_pending()? {
+///         return Ok(false);
+///     }
+///     irq.try_clear_interrupt()?;
+///     Ok(true)
+/// }
+///
+/// fn setup<P: InterruptPin>(irq: &mut P) -> Result<(), P::Error> {
+///     irq.try_enable_interrupt(Trigger::FallingEdge)
+/// }
+/// ```
+pub trait InterruptPin {
+    /// Error type
+    type Error;
+
+    /// Enables the interrupt for the `trigger` condition
+    ///
+    /// This replaces any previously enabled trigger condition.
+    fn try_enable_interrupt(&mut self, trigger: Trigger) -> Result<(), Self::Error>;
+
+    /// Disables the interrupt
+    fn try_disable_interrupt(&mut self) -> Result<(), Self::Error>;
+
+    /// Is the interrupt pending?
+    fn try_is_interrupt_pending(&self) -> Result<bool, Self::Error>;
+
+    /// Clears the pending interrupt
+    ///
+    /// Level triggered interrupts are raised again as long as the level is held.
+    fn try_clear_interrupt(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Pin bias resistor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pull {
+    /// No bias, the pin floats when not driven
+    None,
+    /// Pull-up resistor to the supply voltage
+    Up,
+    /// Pull-down resistor to ground
+    Down,
+}
+
+/// Pin with configurable bias resistors
+///
+/// ```
+/// use embedded_hal::digital::{InputPin, Pull, PullConfig};
+///
+/// /// Button connected to ground
+/// fn is_pressed<P, E>(button: &mut P) -> Result<bool, E>
+/// where
+///     P: InputPin<Error = E> + PullConfig<Error = E>,
+/// {
+///     button.try_set_pull(Pull::Up)?;
+///     button.try_is_low()
+/// }
+/// ```
+pub trait PullConfig {
+    /// Error type
+    type Error;
+
+    /// Configures the bias resistors of the pin
+    fn try_set_pull(&mut self, pull: Pull) -> Result<(), Self::Error>;
+}
+
+/// Output pin drive mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriveMode {
+    /// The pin actively drives both the high and low levels
+    PushPull,
+    /// The pin only drives the low level and floats otherwise
+    ///
+    /// This allows several devices to share a line, pulled high by a resistor.
+    OpenDrain,
+}
+
+/// Output pin that can switch between push-pull and open drain at runtime
+///
+/// ```
+/// use embedded_hal::digital::{DriveMode, DriveModeConfig, OutputPin};
+///
+/// /// Releases a reset line shared with other boards
+/// fn release_reset<P, E>(reset: &mut P) -> Result<(), E>
+/// where
+///     P: OutputPin<Error = E> + DriveModeConfig<Error = E>,
+/// {
+///     reset.try_set_drive_mode(DriveMode::OpenDrain)?;
+///     reset.try_set_high()
+/// }
+/// ```
+pub trait DriveModeConfig {
+    /// Error type
+    type Error;
+
+    /// Configures the drive mode of the pin
+    ///
+    /// The output state of the pin is kept.
+    fn try_set_drive_mode(&mut self, mode: DriveMode) -> Result<(), Self::Error>;
+}
+
+/// Group of output pins driven together
+///
+/// Bit `n` of the written word sets the level of the `n`-th pin of the port, a set bit driving
+/// it high. Implementations should change all the pins at once, so the intermediate values don't
+/// appear on the lines, which matters e.g. for the inputs of a DAC. See
+/// [`group::PinGroup`] for a non-atomic implementation over individual pins, only suitable for
+/// interfaces with a separate strobe or enable line.
+///
+/// ```
+/// use embedded_hal::digital::OutputPort;
+///
+/// /// Writes a byte to an HD44780 display in 4-bit mode
+/// fn write_byte<P: OutputPort<u8>>(data: &mut P, byte: u8) -> Result<(), P::Error> {
+///     data.try_write(byte >> 4)?;
+///     data.try_write(byte & 0x0F)
+/// }
+/// ```
+pub trait OutputPort<W = u8> {
+    /// Error type
+    type Error;
+
+    /// Drives the pins of the port to the levels of the bits of `word`
+    ///
+    /// Bits beyond the width of the port are ignored.
+    fn try_write(&mut self, word: W) -> Result<(), Self::Error>;
+}
+
+/// Group of input pins read together
+///
+/// Bit `n` of the read word is the level of the `n`-th pin of the port, a set bit meaning high.
+/// Implementations should sample all the pins at once; [`group::PinGroup`] reads them one after
+/// the other.
+pub trait InputPort<W = u8> {
+    /// Error type
+    type Error;
+
+    /// Reads the levels of the pins of the port
+    ///
+    /// Bits beyond the width of the port are cleared.
+    fn try_read(&self) -> Result<W, Self::Error>;
+}
+
+/// Output pin drive strength
+///
+/// Implementations map these to the closest setting supported by the hardware.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriveStrength {
+    /// Weakest drive, lowering power consumption and noise
+    Low,
+    /// Default drive of most pins
+    Medium,
+    /// Strongest drive, for high currents or capacitive loads
+    High,
+}
+
+/// Output pin slew rate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlewRate {
+    /// Slow edges, reducing ringing and electromagnetic interference
+    Slow,
+    /// Fast edges, for high frequency signals
+    Fast,
+}
+
+/// Output pin with configurable drive strength and slew rate
+///
+/// This is an optional trait, implemented only by pins supporting these settings.
+///
+/// ```
+/// use embedded_hal::digital::{DriveConfig, DriveStrength, SlewRate};
+///
+/// /// Configures the data line of an LED strip
+/// fn setup<P: DriveConfig>(data: &mut P) -> Result<(), P::Error> {
+///     data.try_set_drive_strength(DriveStrength::High)?;
+///     data.try_set_slew_rate(SlewRate::Fast)
+/// }
+/// ```
+pub trait DriveConfig {
+    /// Error type
+    type Error;
+
+    /// Configures the drive strength of the pin
+    fn try_set_drive_strength(&mut self, strength: DriveStrength) -> Result<(), Self::Error>;
+
+    /// Configures the slew rate of the pin
+    fn try_set_slew_rate(&mut self, rate: SlewRate) -> Result<(), Self::Error>;
+}
+
+/// Output pin generating single pulses of a precise duration
+///
+/// Implementations may use a timer or hardware one-shot output to produce the pulse, or a delay
+/// like [`pulse::DelayPulsePin`].
+///
+/// ```
+/// use embedded_hal::digital::PulsePin;
+///
+/// /// Triggers a measurement of an HC-SR04 ultrasonic sensor
+/// fn trigger<P>(pin: &mut P) -> Result<(), P::Error>
+/// where
+///     P: PulsePin<Time = u32>,
+/// {
+///     // at least 10 us
+///     pin.try_pulse_high(10u32)
+/// }
+/// ```
+pub trait PulsePin {
+    /// Error type
+    type Error;
+
+    /// The unit of time used for the pulse duration
+    type Time;
+
+    /// Drives the pin high for `duration`, then low again
+    ///
+    /// The pin is expected to be low before the pulse. This returns once the pulse has ended.
+    fn try_pulse_high<T>(&mut self, duration: T) -> Result<(), Self::Error>
+    where
+        T: Into<Self::Time>;
+
+    /// Drives the pin low for `duration`, then high again
+    ///
+    /// The pin is expected to be high before the pulse. This returns once the pulse has ended.
+    fn try_pulse_low<T>(&mut self, duration: T) -> Result<(), Self::Error>
+    where
+        T: Into<Self::Time>;
+}
+
+/// Pin able to wake the system up from low power modes
+///
+/// Not all the trigger conditions may be supported in all the low power modes; implementations
+/// return an error for those that aren't.
+///
+/// ```
+/// use embedded_hal::digital::{Trigger, WakeupPin};
+///
+/// /// Arms the button, connected to ground, before entering deep sleep
+/// fn arm<P: WakeupPin>(button: &mut P) -> Result<(), P::Error> {
+///     button.try_enable_wakeup(Trigger::LowLevel)
+/// }
+/// ```
+pub trait WakeupPin {
+    /// Error type
+    type Error;
+
+    /// Enables waking up on the `trigger` condition
+    ///
+    /// This replaces any previously enabled trigger condition.
+    fn try_enable_wakeup(&mut self, trigger: Trigger) -> Result<(), Self::Error>;
+
+    /// Disables waking up on this pin
+    fn try_disable_wakeup(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Output pin that can be released to high impedance
+///
+/// Driving the pin high or low with the [`OutputPin`] methods enables the output again.
+///
+/// ```
+/// use embedded_hal::digital::{OutputPin, TriStatePin};
+///
+/// /// Lights one LED of a charlieplexed matrix, releasing all the other lines
+/// fn light<P, E>(pins: &mut [P], anode: usize, cathode: usize) -> Result<(), E>
+/// where
+///     P: TriStatePin<Error = E>,
+/// {
+///     for pin in pins.iter_mut() {
+///         pin.try_set_floating()?;
+///     }
+///     pins[anode].try_set_high()?;
+///     pins[cathode].try_set_low()
+/// }
+/// ```
+pub trait TriStatePin: OutputPin {
+    /// Stops driving the pin, leaving it floating
+    fn try_set_floating(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Input pin with a configurable hardware glitch filter
+///
+/// The filter ignores level changes shorter than its configured length, which handles electrical
+/// noise, e.g. on long industrial lines. Unlike [`debounced::Debounced`],
+/// it works below the software sampling rate and usually only filters out short glitches, not
+/// the bouncing of mechanical contacts. Implementations return an error for lengths they can't
+/// provide.
+///
+/// ```
+/// use embedded_hal::digital::InputFilterConfig;
+///
+/// /// Rejects glitches shorter than 4 cycles of the filter clock
+/// fn setup<P: InputFilterConfig>(input: &mut P) -> Result<(), P::Error> {
+///     input.try_set_filter_samples(4)
+/// }
+/// ```
+pub trait InputFilterConfig {
+    /// Error type
+    type Error;
+
+    /// The unit of time used for the filter length
+    type Time;
+
+    /// Filters out level changes lasting less than `count` cycles of the filter clock
+    fn try_set_filter_samples(&mut self, count: u8) -> Result<(), Self::Error>;
+
+    /// Filters out level changes shorter than `duration`
+    ///
+    /// Implementations configure the filter clock and sample count to match `duration`, or the
+    /// closest supported length above it.
+    fn try_set_filter_time<T>(&mut self, duration: T) -> Result<(), Self::Error>
+    where
+        T: Into<Self::Time>;
+
+    /// Disables the filter
+    fn try_disable_filter(&mut self) -> Result<(), Self::Error>;
+}
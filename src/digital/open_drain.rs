@@ -0,0 +1,176 @@
+//! Open drain emulation
+//!
+//! An open drain output only drives its line low and lets a pull-up resistor bring it high, so
+//! several devices can share the line, as with I2C or 1-Wire. [`EmulatedOpenDrain`] provides this
+//! on pins without a native open drain mode by switching an [`IoPin`] between output mode,
+//! driving low, and input mode, releasing the line.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::digital::open_drain::EmulatedOpenDrain;
+//! use embedded_hal::digital::{InputPin, OutputPin};
+//! # use core::convert::Infallible;
+//! # use embedded_hal::digital::{IoPin, PinState};
+//! # struct Input;
+//! # struct Output;
+//! # impl InputPin for Input {
+//! #     type Error = Infallible;
+//! #     fn try_is_high(&self) -> Result<bool, Self::Error> { Ok(true) }
+//! #     fn try_is_low(&self) -> Result<bool, Self::Error> { Ok(false) }
+//! # }
+//! # impl OutputPin for Output {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl IoPin<Input, Output> for Input {
+//! #     type Error = Infallible;
+//! #     fn try_into_input(self) -> Result<Input, Self::Error> { Ok(self) }
+//! #     fn try_into_output(self, _: PinState) -> Result<Output, Self::Error> { Ok(Output) }
+//! # }
+//! # impl IoPin<Input, Output> for Output {
+//! #     type Error = Infallible;
+//! #     fn try_into_input(self) -> Result<Input, Self::Error> { Ok(Input) }
+//! #     fn try_into_output(self, _: PinState) -> Result<Output, Self::Error> { Ok(self) }
+//! # }
+//! # let pin = Input;
+//!
+//! let mut line = EmulatedOpenDrain::new(pin);
+//!
+//! // 1-Wire reset pulse
+//! line.try_set_low().unwrap();
+//! line.try_set_high().unwrap();
+//! let presence = line.try_is_low().unwrap();
+//! ```
+
+use super::{InputPin, IoPin, OutputPin, PinState};
+
+wrapper_error! {
+    /// Emulated open drain error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum OpenDrainError<E> {
+        /// Underlying pin error
+        Pin(E) => forward, "pin error",
+        /// The pin was lost in a previous failed mode switch
+        Unavailable => Other, "pin lost in a failed mode switch",
+    }
+
+    impl super::Error => super::ErrorKind where E: super::Error
+}
+
+enum Mode<I, O> {
+    /// Line released
+    Input(I),
+    /// Line driven low
+    Output(O),
+}
+
+/// Open drain output emulated by switching the direction of a pin
+///
+/// `I` and `O` are the types of the pin in input and output mode. The line is released while in
+/// input mode and driven low in output mode. As mode switches consume the pin, **it is lost if a
+/// switch fails**, and all the following operations return [`OpenDrainError::Unavailable`].
+pub struct EmulatedOpenDrain<I, O> {
+    pin: Option<Mode<I, O>>,
+}
+
+impl<I, O, E> EmulatedOpenDrain<I, O>
+where
+    I: InputPin<Error = E> + IoPin<I, O, Error = E>,
+    O: OutputPin<Error = E> + IoPin<I, O, Error = E>,
+{
+    /// Creates a new open drain output from the pin in input mode, releasing the line
+    pub fn new(pin: I) -> Self {
+        Self {
+            pin: Some(Mode::Input(pin)),
+        }
+    }
+
+    /// Destroys the open drain output, returning the pin in input mode
+    pub fn destroy(mut self) -> Result<I, OpenDrainError<E>> {
+        self.release()?;
+        match self.pin {
+            Some(Mode::Input(pin)) => Ok(pin),
+            _ => Err(OpenDrainError::Unavailable),
+        }
+    }
+
+    /// Switches the pin to input mode
+    fn release(&mut self) -> Result<(), OpenDrainError<E>> {
+        match self.pin.take() {
+            Some(Mode::Output(pin)) => {
+                let pin = pin.try_into_input().map_err(OpenDrainError::Pin)?;
+                self.pin = Some(Mode::Input(pin));
+                Ok(())
+            }
+            Some(pin) => {
+                self.pin = Some(pin);
+                Ok(())
+            }
+            None => Err(OpenDrainError::Unavailable),
+        }
+    }
+
+    /// Switches the pin to output mode, driving the line low
+    fn drive_low(&mut self) -> Result<(), OpenDrainError<E>> {
+        match self.pin.take() {
+            Some(Mode::Input(pin)) => {
+                let pin = pin
+                    .try_into_output(PinState::Low)
+                    .map_err(OpenDrainError::Pin)?;
+                self.pin = Some(Mode::Output(pin));
+                Ok(())
+            }
+            Some(pin) => {
+                self.pin = Some(pin);
+                Ok(())
+            }
+            None => Err(OpenDrainError::Unavailable),
+        }
+    }
+}
+
+impl<I, O, E> OutputPin for EmulatedOpenDrain<I, O>
+where
+    I: InputPin<Error = E> + IoPin<I, O, Error = E>,
+    O: OutputPin<Error = E> + IoPin<I, O, Error = E>,
+{
+    type Error = OpenDrainError<E>;
+
+    /// Drives the line low
+    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+        self.drive_low()
+    }
+
+    /// Releases the line, letting it be pulled high
+    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+        self.release()
+    }
+}
+
+impl<I, O, E> InputPin for EmulatedOpenDrain<I, O>
+where
+    I: InputPin<Error = E> + IoPin<I, O, Error = E>,
+    O: OutputPin<Error = E> + IoPin<I, O, Error = E>,
+{
+    type Error = OpenDrainError<E>;
+
+    /// Is the line high?
+    ///
+    /// While the line is driven low, this returns `false` without reading the pin.
+    fn try_is_high(&self) -> Result<bool, Self::Error> {
+        match &self.pin {
+            Some(Mode::Input(pin)) => pin.try_is_high().map_err(OpenDrainError::Pin),
+            Some(Mode::Output(_)) => Ok(false),
+            None => Err(OpenDrainError::Unavailable),
+        }
+    }
+
+    /// Is the line low?
+    ///
+    /// While the line is driven low, this returns `true` without reading the pin.
+    fn try_is_low(&self) -> Result<bool, Self::Error> {
+        self.try_is_high().map(|high| !high)
+    }
+}
@@ -0,0 +1,103 @@
+//! Pulses generated with a delay
+//!
+//! [`DelayPulsePin`] implements [`PulsePin`] for any output pin with a blocking delay. Interrupts
+//! occurring during the pulse lengthen it, so use a timer backed implementation where the
+//! maximum duration matters.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::digital::pulse::DelayPulsePin;
+//! use embedded_hal::digital::PulsePin;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::blocking::delay::DelayUs;
+//! # use embedded_hal::digital::OutputPin;
+//! # struct Pin;
+//! # impl OutputPin for Pin {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # struct Delay;
+//! # impl DelayUs<u32> for Delay {
+//! #     type Error = Infallible;
+//! #     fn try_delay_us(&mut self, _: u32) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let (latch, delay) = (Pin, Delay);
+//!
+//! let mut latch = DelayPulsePin::new(latch, delay);
+//!
+//! // 1 us latch strobe
+//! latch.try_pulse_high(1u32).unwrap();
+//! ```
+
+use super::{OutputPin, PulsePin};
+use crate::blocking::delay::DelayUs;
+
+wrapper_error! {
+    /// Pulse generation error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum PulseError<PinError, DelayError> {
+        /// Underlying pin error
+        Pin(PinError) => forward, "pin error",
+        /// Underlying delay error
+        Delay(DelayError) => Other, "delay error",
+    }
+
+    impl super::Error => super::ErrorKind where PinError: super::Error
+}
+
+/// Output pin generating pulses with a blocking delay
+///
+/// The pulse durations are in microseconds.
+pub struct DelayPulsePin<P, Delay> {
+    pin: P,
+    delay: Delay,
+}
+
+impl<P, Delay> DelayPulsePin<P, Delay>
+where
+    P: OutputPin,
+    Delay: DelayUs<u32>,
+{
+    /// Creates a new pulse generator from the provided pin and delay
+    pub fn new(pin: P, delay: Delay) -> Self {
+        Self { pin, delay }
+    }
+
+    /// Destroys the pulse generator, returning the pin and delay
+    pub fn destroy(self) -> (P, Delay) {
+        (self.pin, self.delay)
+    }
+}
+
+impl<P, Delay> PulsePin for DelayPulsePin<P, Delay>
+where
+    P: OutputPin,
+    Delay: DelayUs<u32>,
+{
+    type Error = PulseError<P::Error, Delay::Error>;
+    type Time = u32;
+
+    fn try_pulse_high<T>(&mut self, duration: T) -> Result<(), Self::Error>
+    where
+        T: Into<u32>,
+    {
+        self.pin.try_set_high().map_err(PulseError::Pin)?;
+        self.delay
+            .try_delay_us(duration.into())
+            .map_err(PulseError::Delay)?;
+        self.pin.try_set_low().map_err(PulseError::Pin)
+    }
+
+    fn try_pulse_low<T>(&mut self, duration: T) -> Result<(), Self::Error>
+    where
+        T: Into<u32>,
+    {
+        self.pin.try_set_low().map_err(PulseError::Pin)?;
+        self.delay
+            .try_delay_us(duration.into())
+            .map_err(PulseError::Delay)?;
+        self.pin.try_set_high().map_err(PulseError::Pin)
+    }
+}
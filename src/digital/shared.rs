@@ -0,0 +1,115 @@
+//! Sharing an output pin between execution contexts
+//!
+//! A [`SharedPin`] takes ownership of a pin and hands out any number of [`PinProxy`] handles
+//! implementing the output pin traits. Each call runs in a critical section, so the pin can be
+//! stored in a `static` and driven from both the main code and interrupt handlers, e.g. an LED
+//! signalling activity from several places.
+//!
+//! This module is only available when the `critical-section` feature is enabled.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::digital::shared::SharedPin;
+//! use embedded_hal::digital::{OutputPin, ToggleableOutputPin};
+//! # use core::convert::Infallible;
+//! # struct Led;
+//! # impl OutputPin for Led {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # impl ToggleableOutputPin for Led {
+//! #     type Error = Infallible;
+//! #     fn try_toggle(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//!
+//! static LED: SharedPin<Led> = SharedPin::new(Led);
+//!
+//! fn on_timer_interrupt() {
+//!     LED.acquire().try_toggle().unwrap();
+//! }
+//!
+//! LED.acquire().try_set_high().unwrap();
+//! on_timer_interrupt();
+//! ```
+
+use core::cell::RefCell;
+
+use super::{OutputPin, StatefulOutputPin, ToggleableOutputPin};
+
+/// Output pin shared between several execution contexts
+pub struct SharedPin<P> {
+    pin: critical_section::Mutex<RefCell<P>>,
+}
+
+impl<P> SharedPin<P> {
+    /// Creates a new shared pin from the provided pin
+    pub const fn new(pin: P) -> Self {
+        Self {
+            pin: critical_section::Mutex::new(RefCell::new(pin)),
+        }
+    }
+
+    /// Creates a new handle to the pin
+    pub fn acquire(&self) -> PinProxy<'_, P> {
+        PinProxy { pin: &self.pin }
+    }
+
+    /// Destroys the shared pin, returning the pin
+    pub fn destroy(self) -> P {
+        self.pin.into_inner().into_inner()
+    }
+}
+
+/// Borrowed access to a [`SharedPin`]
+///
+/// Each call to one of the output pin traits runs in a critical section.
+pub struct PinProxy<'a, P> {
+    pin: &'a critical_section::Mutex<RefCell<P>>,
+}
+
+impl<'a, P> PinProxy<'a, P> {
+    fn with<R>(&self, f: impl FnOnce(&mut P) -> R) -> R {
+        critical_section::with(|cs| f(&mut self.pin.borrow_ref_mut(cs)))
+    }
+}
+
+impl<'a, P> OutputPin for PinProxy<'a, P>
+where
+    P: OutputPin,
+{
+    type Error = P::Error;
+
+    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+        self.with(|pin| pin.try_set_low())
+    }
+
+    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+        self.with(|pin| pin.try_set_high())
+    }
+}
+
+impl<'a, P> StatefulOutputPin for PinProxy<'a, P>
+where
+    P: StatefulOutputPin,
+{
+    fn try_is_set_high(&self) -> Result<bool, Self::Error> {
+        self.with(|pin| pin.try_is_set_high())
+    }
+
+    fn try_is_set_low(&self) -> Result<bool, Self::Error> {
+        self.with(|pin| pin.try_is_set_low())
+    }
+}
+
+impl<'a, P> ToggleableOutputPin for PinProxy<'a, P>
+where
+    P: ToggleableOutputPin,
+{
+    type Error = P::Error;
+
+    fn try_toggle(&mut self) -> Result<(), Self::Error> {
+        self.with(|pin| pin.try_toggle())
+    }
+}
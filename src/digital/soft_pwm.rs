@@ -0,0 +1,199 @@
+//! Software PWM over an output pin
+//!
+//! [`SoftPwmPin`] implements [`PwmPin`] by toggling an output pin on the ticks of a periodic
+//! [`CountDown`], for dimming LEDs on pins without hardware PWM. A PWM period is made of
+//! `max_duty` ticks, the pin being high for the first `duty` of them.
+//!
+//! The pin is only updated from [`try_poll`](SoftPwmPin::try_poll), which must be called at least
+//! once per tick, e.g. from the timer interrupt or the main loop. This limits it to low
+//! frequencies, and any delay in polling shows as jitter.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::digital::soft_pwm::SoftPwmPin;
+//! use embedded_hal::pwm::PwmPin;
+//! # use core::convert::Infallible;
+//! # use embedded_hal::digital::OutputPin;
+//! # use embedded_hal::timer::{CountDown, Periodic};
+//! # struct Led;
+//! # impl OutputPin for Led {
+//! #     type Error = Infallible;
+//! #     fn try_set_low(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_set_high(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # #[derive(Clone)]
+//! # struct Micros(u32);
+//! # struct Timer;
+//! # impl CountDown for Timer {
+//! #     type Error = Infallible;
+//! #     type Time = Micros;
+//! #     fn try_start<T>(&mut self, _: T) -> Result<(), Self::Error> where T: Into<Micros> { Ok(()) }
+//! #     fn try_wait(&mut self) -> nb::Result<(), Infallible> { Ok(()) }
+//! # }
+//! # impl Periodic for Timer {}
+//! # let (led, timer) = (Led, Timer);
+//!
+//! // 100 steps of 100 us, i.e. a 100 Hz PWM
+//! let mut led = SoftPwmPin::new(led, timer, Micros(100), 100);
+//! led.try_set_duty(25).unwrap();
+//! led.try_enable().unwrap();
+//!
+//! # for _ in 0..100 {
+//! loop {
+//!     nb::block!(led.try_poll()).unwrap();
+//! #   break;
+//! }
+//! # }
+//! ```
+
+use super::OutputPin;
+use crate::pwm::PwmPin;
+use crate::timer::{CountDown, Periodic};
+
+wrapper_error! {
+    /// Software PWM error
+    #[derive(Clone, Debug, PartialEq)]
+    pub enum SoftPwmError<PinError, TimerError> {
+        /// Underlying pin error
+        Pin(PinError) => forward, "pin error",
+        /// Underlying timer error
+        Timer(TimerError) => Other, "timer error",
+    }
+
+    impl super::Error => super::ErrorKind where PinError: super::Error
+}
+
+/// PWM output driven in software
+pub struct SoftPwmPin<P, Timer: CountDown> {
+    pin: P,
+    timer: Timer,
+    tick: Timer::Time,
+    max_duty: u16,
+    duty: u16,
+    /// Current tick within the PWM period
+    step: u16,
+    /// Level the pin is driven to
+    high: bool,
+    enabled: bool,
+}
+
+impl<P, Timer> SoftPwmPin<P, Timer>
+where
+    P: OutputPin,
+    Timer: CountDown + Periodic,
+    Timer::Time: Clone,
+{
+    /// Creates a new disabled PWM output
+    ///
+    /// `tick` is the timer period of each step and `max_duty` the number of steps of a PWM
+    /// period. The duty cycle starts at 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_duty` is 0.
+    pub fn new<T>(pin: P, timer: Timer, tick: T, max_duty: u16) -> Self
+    where
+        T: Into<Timer::Time>,
+    {
+        assert!(max_duty > 0, "max_duty must be at least 1");
+
+        Self {
+            pin,
+            timer,
+            tick: tick.into(),
+            max_duty,
+            duty: 0,
+            step: 0,
+            high: false,
+            enabled: false,
+        }
+    }
+
+    /// Destroys the PWM output, returning the pin and timer
+    pub fn destroy(self) -> (P, Timer) {
+        (self.pin, self.timer)
+    }
+
+    /// Updates the pin on the next tick
+    ///
+    /// This returns `WouldBlock` until the timer ticks, and `Ok` immediately while disabled.
+    pub fn try_poll(&mut self) -> nb::Result<(), SoftPwmError<P::Error, Timer::Error>> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        self.timer
+            .try_wait()
+            .map_err(|e| e.map(SoftPwmError::Timer))?;
+
+        self.step = (self.step + 1) % self.max_duty;
+        self.update().map_err(nb::Error::Other)
+    }
+
+    /// Drives the pin to the level of the current step
+    fn update(&mut self) -> Result<(), SoftPwmError<P::Error, Timer::Error>> {
+        let high = self.step < self.duty;
+        if high != self.high {
+            if high {
+                self.pin.try_set_high().map_err(SoftPwmError::Pin)?;
+            } else {
+                self.pin.try_set_low().map_err(SoftPwmError::Pin)?;
+            }
+            self.high = high;
+        }
+
+        Ok(())
+    }
+}
+
+impl<P, Timer> PwmPin for SoftPwmPin<P, Timer>
+where
+    P: OutputPin,
+    Timer: CountDown + Periodic,
+    Timer::Time: Clone,
+{
+    type Error = SoftPwmError<P::Error, Timer::Error>;
+    type Duty = u16;
+
+    /// Drives the pin low and stops updating it
+    fn try_disable(&mut self) -> Result<(), Self::Error> {
+        self.enabled = false;
+        self.pin.try_set_low().map_err(SoftPwmError::Pin)?;
+        self.high = false;
+
+        Ok(())
+    }
+
+    /// Starts a new PWM period
+    fn try_enable(&mut self) -> Result<(), Self::Error> {
+        self.timer
+            .try_start(self.tick.clone())
+            .map_err(SoftPwmError::Timer)?;
+        self.enabled = true;
+        self.step = 0;
+        self.high = self.duty > 0;
+        if self.high {
+            self.pin.try_set_high().map_err(SoftPwmError::Pin)
+        } else {
+            self.pin.try_set_low().map_err(SoftPwmError::Pin)
+        }
+    }
+
+    fn try_get_duty(&self) -> Result<Self::Duty, Self::Error> {
+        Ok(self.duty)
+    }
+
+    fn try_get_max_duty(&self) -> Result<Self::Duty, Self::Error> {
+        Ok(self.max_duty)
+    }
+
+    /// Sets a new duty cycle, taking effect on the next tick
+    ///
+    /// Values above the maximum duty cycle are clamped to it.
+    fn try_set_duty(&mut self, duty: Self::Duty) -> Result<(), Self::Error> {
+        self.duty = duty.min(self.max_duty);
+
+        Ok(())
+    }
+}
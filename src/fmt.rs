@@ -1,18 +1,81 @@
 //! Implementation of `core::fmt::Write` for the HAL's `serial::Write`.
 //!
-//! TODO write example of usage
+//! Blocking serial writers can be used with `write!` and `writeln!` through [`SerialWriteFmt`]:
+//!
+//! ```
+//! use core::fmt::Write;
+//! use embedded_hal::fmt::SerialWriteFmt;
+//! # use core::convert::Infallible;
+//! # struct Uart;
+//! # impl embedded_hal::blocking::serial::Write<u8> for Uart {
+//! #     type Error = Infallible;
+//! #     fn try_bwrite_all(&mut self, _: &[u8]) -> Result<(), Self::Error> { Ok(()) }
+//! #     fn try_bflush(&mut self) -> Result<(), Self::Error> { Ok(()) }
+//! # }
+//! # let uart = Uart;
+//!
+//! let mut console = SerialWriteFmt::new(uart);
+//! writeln!(console, "temperature: {} C", 21).unwrap();
+//! ```
 use core::fmt::{Result, Write};
 
-impl<Word, Error> Write for dyn (crate::serial::Write<Word, Error = Error>)
+impl<Word, Error> Write for dyn crate::serial::Write<Word, Error = Error>
 where
     Word: From<u8>,
 {
     fn write_str(&mut self, s: &str) -> Result {
         let _ = s
             .as_bytes()
-            .into_iter()
+            .iter()
             .map(|c| nb::block!(self.try_write(Word::from(*c))))
             .last();
         Ok(())
     }
 }
+
+/// Adapter implementing `core::fmt::Write` for blocking serial writers
+///
+/// Errors of the serial writer are reported as `core::fmt::Error`, which carries no details; use
+/// [`take_error`](SerialWriteFmt::take_error) to retrieve the last one.
+pub struct SerialWriteFmt<S>
+where
+    S: crate::blocking::serial::Write<u8>,
+{
+    serial: S,
+    error: Option<S::Error>,
+}
+
+impl<S> SerialWriteFmt<S>
+where
+    S: crate::blocking::serial::Write<u8>,
+{
+    /// Wraps the serial writer `serial`
+    pub fn new(serial: S) -> Self {
+        Self {
+            serial,
+            error: None,
+        }
+    }
+
+    /// Returns the last error of the serial writer, if any, clearing it
+    pub fn take_error(&mut self) -> Option<S::Error> {
+        self.error.take()
+    }
+
+    /// Destroys the adapter, returning the serial writer
+    pub fn destroy(self) -> S {
+        self.serial
+    }
+}
+
+impl<S> Write for SerialWriteFmt<S>
+where
+    S: crate::blocking::serial::Write<u8>,
+{
+    fn write_str(&mut self, s: &str) -> Result {
+        self.serial.try_bwrite_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            core::fmt::Error
+        })
+    }
+}
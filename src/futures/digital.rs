@@ -0,0 +1,182 @@
+//! Asynchronous digital I/O
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::futures::digital::Wait;
+//!
+//! async fn wait_for_data_ready<P: Wait>(irq: &mut P) -> Result<(), P::Error> {
+//!     // the sensor pulls its interrupt line low when a sample is available
+//!     irq.wait_for_falling_edge().await
+//! }
+//! ```
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use super::FromBlocking;
+use crate::digital::InputPin;
+
+/// Asynchronously waiting for the level of an input pin
+///
+/// Implementations are usually backed by a pin interrupt, waking the task when the level
+/// changes. Edges are detected from the moment the future is first polled.
+pub trait Wait {
+    /// Error type
+    type Error;
+
+    /// Future returned by `wait_for_high`
+    type WaitForHighFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Future returned by `wait_for_low`
+    type WaitForLowFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Future returned by `wait_for_rising_edge`
+    type WaitForRisingEdgeFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Future returned by `wait_for_falling_edge`
+    type WaitForFallingEdgeFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Future returned by `wait_for_any_edge`
+    type WaitForAnyEdgeFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Waits until the pin is high, completing immediately if it already is
+    fn wait_for_high<'a>(&'a mut self) -> Self::WaitForHighFuture<'a>;
+
+    /// Waits until the pin is low, completing immediately if it already is
+    fn wait_for_low<'a>(&'a mut self) -> Self::WaitForLowFuture<'a>;
+
+    /// Waits for the pin to go from low to high
+    fn wait_for_rising_edge<'a>(&'a mut self) -> Self::WaitForRisingEdgeFuture<'a>;
+
+    /// Waits for the pin to go from high to low
+    fn wait_for_falling_edge<'a>(&'a mut self) -> Self::WaitForFallingEdgeFuture<'a>;
+
+    /// Waits for the pin to change level
+    fn wait_for_any_edge<'a>(&'a mut self) -> Self::WaitForAnyEdgeFuture<'a>;
+}
+
+/// Condition awaited by a [`PollWait`] future
+#[derive(Clone, Copy)]
+enum Condition {
+    High,
+    Low,
+    RisingEdge,
+    FallingEdge,
+    AnyEdge,
+}
+
+/// Future polling a blocking input pin until a condition is met
+///
+/// Each poll reads the pin and, if the condition isn't met yet, immediately wakes the task again,
+/// so waiting keeps the executor busy. Short pulses between two polls are missed.
+pub struct PollWait<'a, T> {
+    pin: &'a T,
+    condition: Condition,
+    last: Option<bool>,
+}
+
+impl<'a, T> Future for PollWait<'a, T>
+where
+    T: InputPin,
+{
+    type Output = Result<(), T::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let high = this.pin.try_is_high()?;
+        let last = this.last.replace(high);
+
+        let done = match (this.condition, last) {
+            (Condition::High, _) => high,
+            (Condition::Low, _) => !high,
+            (_, None) => false,
+            (Condition::RisingEdge, Some(last)) => !last && high,
+            (Condition::FallingEdge, Some(last)) => last && !high,
+            (Condition::AnyEdge, Some(last)) => last != high,
+        };
+
+        if done {
+            Poll::Ready(Ok(()))
+        } else {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+impl<T> FromBlocking<T>
+where
+    T: InputPin,
+{
+    fn poll_wait(&mut self, condition: Condition) -> PollWait<'_, T> {
+        PollWait {
+            pin: &self.inner,
+            condition,
+            last: None,
+        }
+    }
+}
+
+impl<T> Wait for FromBlocking<T>
+where
+    T: InputPin,
+{
+    type Error = T::Error;
+
+    type WaitForHighFuture<'a>
+        = PollWait<'a, T>
+    where
+        Self: 'a;
+
+    type WaitForLowFuture<'a>
+        = PollWait<'a, T>
+    where
+        Self: 'a;
+
+    type WaitForRisingEdgeFuture<'a>
+        = PollWait<'a, T>
+    where
+        Self: 'a;
+
+    type WaitForFallingEdgeFuture<'a>
+        = PollWait<'a, T>
+    where
+        Self: 'a;
+
+    type WaitForAnyEdgeFuture<'a>
+        = PollWait<'a, T>
+    where
+        Self: 'a;
+
+    fn wait_for_high<'a>(&'a mut self) -> Self::WaitForHighFuture<'a> {
+        self.poll_wait(Condition::High)
+    }
+
+    fn wait_for_low<'a>(&'a mut self) -> Self::WaitForLowFuture<'a> {
+        self.poll_wait(Condition::Low)
+    }
+
+    fn wait_for_rising_edge<'a>(&'a mut self) -> Self::WaitForRisingEdgeFuture<'a> {
+        self.poll_wait(Condition::RisingEdge)
+    }
+
+    fn wait_for_falling_edge<'a>(&'a mut self) -> Self::WaitForFallingEdgeFuture<'a> {
+        self.poll_wait(Condition::FallingEdge)
+    }
+
+    fn wait_for_any_edge<'a>(&'a mut self) -> Self::WaitForAnyEdgeFuture<'a> {
+        self.poll_wait(Condition::AnyEdge)
+    }
+}
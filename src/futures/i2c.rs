@@ -0,0 +1,160 @@
+//! Asynchronous I2C API
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::futures::i2c::WriteRead;
+//!
+//! async fn read_temperature<I: WriteRead>(i2c: &mut I) -> Result<[u8; 2], I::Error> {
+//!     let mut buf = [0; 2];
+//!     i2c.write_read(0x48, &[0x00], &mut buf).await?;
+//!
+//!     Ok(buf)
+//! }
+//! ```
+
+use core::future::{ready, Future, Ready};
+
+use super::FromBlocking;
+use crate::blocking::i2c::{AddressMode, Operation, SevenBitAddress};
+
+/// Asynchronous read
+pub trait Read<A: AddressMode = SevenBitAddress> {
+    /// Error type
+    type Error;
+
+    /// Future returned by `read`
+    type ReadFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Reads enough bytes from the slave with `address` to fill `buffer`
+    fn read<'a>(&'a mut self, address: A, buffer: &'a mut [u8]) -> Self::ReadFuture<'a>;
+}
+
+/// Asynchronous write
+pub trait Write<A: AddressMode = SevenBitAddress> {
+    /// Error type
+    type Error;
+
+    /// Future returned by `write`
+    type WriteFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Writes `bytes` to the slave with `address`
+    fn write<'a>(&'a mut self, address: A, bytes: &'a [u8]) -> Self::WriteFuture<'a>;
+}
+
+/// Asynchronous write followed by a read, with a repeated start in between
+pub trait WriteRead<A: AddressMode = SevenBitAddress> {
+    /// Error type
+    type Error;
+
+    /// Future returned by `write_read`
+    type WriteReadFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Writes `bytes` to the slave with `address`, then reads enough bytes to fill `buffer`
+    fn write_read<'a>(
+        &'a mut self,
+        address: A,
+        bytes: &'a [u8],
+        buffer: &'a mut [u8],
+    ) -> Self::WriteReadFuture<'a>;
+}
+
+/// Asynchronous transactional interface
+///
+/// This allows multiple operations to be executed as part of a single I2C transaction, with
+/// the same bus semantics as [`blocking::i2c::Transactional`](crate::blocking::i2c::Transactional).
+pub trait Transactional<A: AddressMode = SevenBitAddress> {
+    /// Error type
+    type Error;
+
+    /// Future returned by `exec`
+    type ExecFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Executes the provided operations on the slave with `address`
+    fn exec<'a>(
+        &'a mut self,
+        address: A,
+        operations: &'a mut [Operation<'a>],
+    ) -> Self::ExecFuture<'a>;
+}
+
+impl<A: AddressMode + 'static, T> Read<A> for FromBlocking<T>
+where
+    T: crate::blocking::i2c::Read<A>,
+{
+    type Error = T::Error;
+
+    type ReadFuture<'a>
+        = Ready<Result<(), T::Error>>
+    where
+        Self: 'a;
+
+    fn read<'a>(&'a mut self, address: A, buffer: &'a mut [u8]) -> Self::ReadFuture<'a> {
+        ready(self.inner.try_read(address, buffer))
+    }
+}
+
+impl<A: AddressMode + 'static, T> Write<A> for FromBlocking<T>
+where
+    T: crate::blocking::i2c::Write<A>,
+{
+    type Error = T::Error;
+
+    type WriteFuture<'a>
+        = Ready<Result<(), T::Error>>
+    where
+        Self: 'a;
+
+    fn write<'a>(&'a mut self, address: A, bytes: &'a [u8]) -> Self::WriteFuture<'a> {
+        ready(self.inner.try_write(address, bytes))
+    }
+}
+
+impl<A: AddressMode + 'static, T> WriteRead<A> for FromBlocking<T>
+where
+    T: crate::blocking::i2c::WriteRead<A>,
+{
+    type Error = T::Error;
+
+    type WriteReadFuture<'a>
+        = Ready<Result<(), T::Error>>
+    where
+        Self: 'a;
+
+    fn write_read<'a>(
+        &'a mut self,
+        address: A,
+        bytes: &'a [u8],
+        buffer: &'a mut [u8],
+    ) -> Self::WriteReadFuture<'a> {
+        ready(self.inner.try_write_read(address, bytes, buffer))
+    }
+}
+
+impl<A: AddressMode + 'static, T> Transactional<A> for FromBlocking<T>
+where
+    T: crate::blocking::i2c::Transactional<A>,
+{
+    type Error = T::Error;
+
+    type ExecFuture<'a>
+        = Ready<Result<(), T::Error>>
+    where
+        Self: 'a;
+
+    fn exec<'a>(
+        &'a mut self,
+        address: A,
+        operations: &'a mut [Operation<'a>],
+    ) -> Self::ExecFuture<'a> {
+        ready(self.inner.try_exec(address, operations))
+    }
+}
@@ -0,0 +1,45 @@
+//! Asynchronous APIs
+//!
+//! The traits in this module return futures rather than `nb::Result`s, so drivers can be written
+//! as `async` functions and driven by an executor. The returned futures are associated types
+//! with a lifetime parameter, allowing implementations to name their concrete future type
+//! without boxing.
+//!
+//! Existing blocking implementations can be used through these traits by wrapping them in
+//! [`FromBlocking`]. The returned futures are then ready immediately, as the blocking operation
+//! runs to completion when the future is created. Waiting for the level of a blocking input pin
+//! polls it instead, see [`digital::PollWait`].
+//!
+//! This module is only available when the `futures` feature is enabled, which requires Rust
+//! 1.65 or newer.
+
+pub mod digital;
+pub mod i2c;
+pub mod serial;
+pub mod spi;
+
+/// Adapter implementing the asynchronous traits for blocking implementations
+///
+/// Each operation runs the corresponding blocking operation to completion and returns an
+/// already completed future, which allows migrating drivers to the asynchronous traits before
+/// the underlying implementation supports them.
+pub struct FromBlocking<T> {
+    inner: T,
+}
+
+impl<T> FromBlocking<T> {
+    /// Wraps the blocking implementation `inner`
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a mutable reference to the wrapped implementation
+    pub fn inner(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Destroys the adapter, returning the wrapped implementation
+    pub fn destroy(self) -> T {
+        self.inner
+    }
+}
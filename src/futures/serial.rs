@@ -0,0 +1,103 @@
+//! Asynchronous serial API
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::futures::serial::{Read, Write};
+//!
+//! /// Sends an AT command and reads the beginning of the response
+//! async fn command<S, E>(modem: &mut S, response: &mut [u8]) -> Result<usize, E>
+//! where
+//!     S: Read<u8, Error = E> + Write<u8, Error = E>,
+//! {
+//!     modem.write(b"AT\r\n").await?;
+//!     modem.flush().await?;
+//!     modem.read(response).await
+//! }
+//! ```
+
+use core::future::{ready, Future, Ready};
+
+use super::FromBlocking;
+
+/// Asynchronous read
+pub trait Read<W: 'static = u8> {
+    /// Error type
+    type Error;
+
+    /// Future returned by `read`
+    type ReadFuture<'a>: Future<Output = Result<usize, Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Reads words into `buffer`, returning how many were read
+    ///
+    /// The future completes once at least one word has been read, unless `buffer` is empty.
+    fn read<'a>(&'a mut self, buffer: &'a mut [W]) -> Self::ReadFuture<'a>;
+}
+
+/// Asynchronous write
+pub trait Write<W: 'static = u8> {
+    /// Error type
+    type Error;
+
+    /// Future returned by `write`
+    type WriteFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Future returned by `flush`
+    type FlushFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Writes all the `words`
+    ///
+    /// The future may complete once the words have been buffered, before they are sent.
+    fn write<'a>(&'a mut self, words: &'a [W]) -> Self::WriteFuture<'a>;
+
+    /// Waits until all the written words have been sent
+    fn flush<'a>(&'a mut self) -> Self::FlushFuture<'a>;
+}
+
+impl<W: 'static, T> Read<W> for FromBlocking<T>
+where
+    T: crate::blocking::serial::Read<W>,
+{
+    type Error = T::Error;
+
+    type ReadFuture<'a>
+        = Ready<Result<usize, T::Error>>
+    where
+        Self: 'a;
+
+    /// Fills the whole buffer
+    fn read<'a>(&'a mut self, buffer: &'a mut [W]) -> Self::ReadFuture<'a> {
+        ready(self.inner.try_read_exact(buffer).map(|()| buffer.len()))
+    }
+}
+
+impl<W: 'static, T> Write<W> for FromBlocking<T>
+where
+    T: crate::blocking::serial::Write<W>,
+{
+    type Error = T::Error;
+
+    type WriteFuture<'a>
+        = Ready<Result<(), T::Error>>
+    where
+        Self: 'a;
+
+    type FlushFuture<'a>
+        = Ready<Result<(), T::Error>>
+    where
+        Self: 'a;
+
+    fn write<'a>(&'a mut self, words: &'a [W]) -> Self::WriteFuture<'a> {
+        ready(self.inner.try_bwrite_all(words))
+    }
+
+    fn flush<'a>(&'a mut self) -> Self::FlushFuture<'a> {
+        ready(self.inner.try_bflush())
+    }
+}
@@ -0,0 +1,111 @@
+//! Asynchronous SPI API
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::futures::spi::Transfer;
+//!
+//! async fn read_jedec_id<S: Transfer<u8>>(spi: &mut S) -> Result<[u8; 3], S::Error> {
+//!     let mut buf = [0x9F, 0, 0, 0];
+//!     spi.transfer(&mut buf).await?;
+//!
+//!     Ok([buf[1], buf[2], buf[3]])
+//! }
+//! ```
+
+use core::future::{ready, Future, Ready};
+
+use super::FromBlocking;
+use crate::blocking::spi::Operation;
+
+/// Asynchronous transfer
+pub trait Transfer<W: 'static = u8> {
+    /// Error type
+    type Error;
+
+    /// Future returned by `transfer`
+    type TransferFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Sends `words` to the slave, replacing them with the words received from the slave
+    fn transfer<'a>(&'a mut self, words: &'a mut [W]) -> Self::TransferFuture<'a>;
+}
+
+/// Asynchronous write
+pub trait Write<W: 'static = u8> {
+    /// Error type
+    type Error;
+
+    /// Future returned by `write`
+    type WriteFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Sends `words` to the slave, ignoring all the incoming words
+    fn write<'a>(&'a mut self, words: &'a [W]) -> Self::WriteFuture<'a>;
+}
+
+/// Asynchronous transactional interface
+///
+/// This allows multiple actions to be executed as part of a single SPI transaction.
+pub trait Transactional<W: 'static = u8> {
+    /// Error type
+    type Error;
+
+    /// Future returned by `exec`
+    type ExecFuture<'a>: Future<Output = Result<(), Self::Error>> + 'a
+    where
+        Self: 'a;
+
+    /// Executes the provided operations
+    fn exec<'a>(&'a mut self, operations: &'a mut [Operation<'a, W>]) -> Self::ExecFuture<'a>;
+}
+
+impl<W: 'static, T> Transfer<W> for FromBlocking<T>
+where
+    T: crate::blocking::spi::Transfer<W>,
+{
+    type Error = T::Error;
+
+    type TransferFuture<'a>
+        = Ready<Result<(), T::Error>>
+    where
+        Self: 'a;
+
+    fn transfer<'a>(&'a mut self, words: &'a mut [W]) -> Self::TransferFuture<'a> {
+        ready(self.inner.try_transfer(words).map(|_| ()))
+    }
+}
+
+impl<W: 'static, T> Write<W> for FromBlocking<T>
+where
+    T: crate::blocking::spi::Write<W>,
+{
+    type Error = T::Error;
+
+    type WriteFuture<'a>
+        = Ready<Result<(), T::Error>>
+    where
+        Self: 'a;
+
+    fn write<'a>(&'a mut self, words: &'a [W]) -> Self::WriteFuture<'a> {
+        ready(self.inner.try_write(words))
+    }
+}
+
+impl<W: 'static, T> Transactional<W> for FromBlocking<T>
+where
+    T: crate::blocking::spi::Transactional<W>,
+{
+    type Error = T::Error;
+
+    type ExecFuture<'a>
+        = Ready<Result<(), T::Error>>
+    where
+        Self: 'a;
+
+    fn exec<'a>(&'a mut self, operations: &'a mut [Operation<'a, W>]) -> Self::ExecFuture<'a> {
+        ready(self.inner.try_exec(operations))
+    }
+}
@@ -0,0 +1,304 @@
+//! Non-blocking I2C API
+//!
+//! The blocking master API is in [`blocking::i2c`](crate::blocking::i2c). This module contains
+//! the [`Target`] trait for peripherals acting as an I2C target (slave), which respond to a
+//! master instead of initiating transfers and so can't block on them, the [`Configure`] trait
+//! to change the bus frequency at runtime and the [`dma`] traits for transfers running in the
+//! background.
+//!
+//! ## Examples
+//!
+//! A device exposing registers, written with the register index followed by the new values and
+//! read back starting at the last written index:
+//!
+//! ```
+//! use embedded_hal::i2c::{Event, Target};
+//!
+//! fn serve<T: Target>(i2c: &mut T, registers: &mut [u8; 16]) -> Result<(), T::Error> {
+//!     let mut index = 0;
+//!     i2c.try_listen(0x2A)?;
+//!
+//!     loop {
+//!         match nb::block!(i2c.try_event())? {
+//!             Event::Write(_) => {
+//!                 let mut buf = [0; 17];
+//!                 let len = nb::block!(i2c.try_receive(&mut buf))?;
+//!                 if len > 0 {
+//!                     index = usize::from(buf[0]) % registers.len();
+//!                     for (i, byte) in buf[1..len].iter().enumerate() {
+//!                         registers[(index + i) % registers.len()] = *byte;
+//!                     }
+//!                 }
+//!             }
+//!             Event::Read(_) => {
+//!                 nb::block!(i2c.try_respond(&registers[index..]))?;
+//!             }
+//!             Event::Stop => {}
+//!         }
+//! #       break Ok(());
+//!     }
+//! }
+//! ```
+
+use nb;
+
+use crate::blocking::i2c::{AddressMode, SevenBitAddress};
+
+/// I2C error
+///
+/// Implemented by the error types of I2C implementations so generic drivers can react to
+/// specific failures without knowing the concrete error type, e.g. to tell an absent device
+/// apart from a bus fault:
+///
+/// ```
+/// use embedded_hal::blocking::i2c::Write;
+/// use embedded_hal::i2c::{Error, ErrorKind, NoAcknowledgeSource};
+///
+/// fn is_present<I2C>(i2c: &mut I2C, address: u8) -> Result<bool, I2C::Error>
+/// where
+///     I2C: Write,
+///     I2C::Error: Error,
+/// {
+///     match i2c.try_write(address, &[]) {
+///         Ok(()) => Ok(true),
+///         Err(e) if e.kind() == ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address) => {
+///             Ok(false)
+///         }
+///         Err(e) => Err(e),
+///     }
+/// }
+/// ```
+pub trait Error: core::fmt::Debug {
+    /// Converts the error to a generic I2C error kind
+    ///
+    /// Implementations should return `ErrorKind::Other` for errors that don't match any of the
+    /// generic kinds.
+    fn kind(&self) -> ErrorKind;
+}
+
+impl Error for core::convert::Infallible {
+    fn kind(&self) -> ErrorKind {
+        match *self {}
+    }
+}
+
+/// Generic I2C error kind
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A misplaced start or stop condition was detected on the bus
+    Bus,
+    /// Another master won the arbitration for the bus
+    ArbitrationLoss,
+    /// The slave did not acknowledge a byte
+    NoAcknowledge(NoAcknowledgeSource),
+    /// Data was received before the previously received data was read
+    Overrun,
+    /// A slave held SCL low for longer than the limit of the implementation
+    ///
+    /// This usually means that the slave is wedged rather than slow, and that the bus needs to
+    /// be recovered, e.g. with [`BusClear`](crate::blocking::i2c::BusClear).
+    ClockStretchTimeout,
+    /// A transaction took longer than the limit of the implementation
+    Timeout,
+    /// A different error occurred
+    Other,
+}
+
+/// The byte that was not acknowledged
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoAcknowledgeSource {
+    /// The address byte, usually meaning that no device with that address is present
+    Address,
+    /// A data byte
+    Data,
+    /// The implementation can't tell which kind of byte was not acknowledged
+    Unknown,
+}
+
+impl core::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ErrorKind::Bus => write!(f, "a misplaced start or stop condition was detected"),
+            ErrorKind::ArbitrationLoss => write!(f, "arbitration for the bus was lost"),
+            ErrorKind::NoAcknowledge(s) => write!(f, "{}", s),
+            ErrorKind::Overrun => write!(f, "received data was overwritten before being read"),
+            ErrorKind::ClockStretchTimeout => write!(f, "the clock was stretched for too long"),
+            ErrorKind::Timeout => write!(f, "the transaction took too long"),
+            ErrorKind::Other => write!(f, "an unspecified error occurred"),
+        }
+    }
+}
+
+impl core::fmt::Display for NoAcknowledgeSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NoAcknowledgeSource::Address => write!(f, "the address was not acknowledged"),
+            NoAcknowledgeSource::Data => write!(f, "the data was not acknowledged"),
+            NoAcknowledgeSource::Unknown => write!(f, "a byte was not acknowledged"),
+        }
+    }
+}
+
+/// Runtime configuration of an I2C master
+///
+/// ```
+/// use embedded_hal::blocking::i2c::WriteRead;
+/// use embedded_hal::i2c::Configure;
+///
+/// /// Reads the device at the fastest frequency it supports, then restores standard mode
+/// fn read_fast<I2C, E>(i2c: &mut I2C, buffer: &mut [u8]) -> Result<(), E>
+/// where
+///     I2C: WriteRead<Error = E> + Configure<Error = E>,
+/// {
+///     i2c.try_set_frequency(1_000_000)?;
+///     let result = i2c.try_write_read(0x68, &[0x3B], buffer);
+///     i2c.try_set_frequency(100_000)?;
+///
+///     result
+/// }
+/// ```
+pub trait Configure {
+    /// Error type
+    type Error;
+
+    /// Sets the SCL frequency to `hz`
+    ///
+    /// Implementations use the highest frequency they support not above `hz`, and return an
+    /// error if they can't run that slowly.
+    fn try_set_frequency(&mut self, hz: u32) -> Result<(), Self::Error>;
+}
+
+/// Bus event seen by an I2C target
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Event<A = SevenBitAddress> {
+    /// A start or repeated start addressed the target, followed by a write from the master
+    ///
+    /// The written bytes are returned by [`Target::try_receive`].
+    Write(A),
+    /// A start or repeated start addressed the target, followed by a read from the master
+    ///
+    /// The bytes to return to the master are provided with [`Target::try_respond`].
+    Read(A),
+    /// A stop condition ended the transfer
+    Stop,
+}
+
+/// I2C target (slave) interface
+pub trait Target<A: AddressMode = SevenBitAddress> {
+    /// Error type
+    type Error;
+
+    /// Starts responding to transfers addressed to `address`
+    ///
+    /// Implementations supporting several addresses may be called again to listen to more of
+    /// them, others replace the previous address.
+    fn try_listen(&mut self, address: A) -> Result<(), Self::Error>;
+
+    /// Waits for the next bus event concerning this target
+    fn try_event(&mut self) -> nb::Result<Event<A>, Self::Error>;
+
+    /// Receives the bytes written by the master after an [`Event::Write`] into `buffer`,
+    /// returning how many were received
+    ///
+    /// This completes once `buffer` is full or the master ends the write with a stop or
+    /// repeated start, which is then reported by `try_event`. Bytes written after `buffer` is
+    /// full are not acknowledged.
+    fn try_receive(&mut self, buffer: &mut [u8]) -> nb::Result<usize, Self::Error>;
+
+    /// Sends `bytes` to the master after an [`Event::Read`], returning how many were read
+    ///
+    /// This completes once the master stops acknowledging bytes. If the master reads more than
+    /// `bytes`, implementations send `0xFF` until it stops, without counting these bytes.
+    fn try_respond(&mut self, bytes: &[u8]) -> nb::Result<usize, Self::Error>;
+}
+
+/// DMA backed (master mode) transfers
+///
+/// A transfer is split into a starting and a completion step so the CPU is free to do other
+/// work while the peripheral moves the data, e.g. a display framebuffer or a page of an EEPROM.
+/// Buffers are required to be `'static` as the DMA engine keeps accessing them after the
+/// transfer starts; ownership is handed back by
+/// [`TransferWait::try_wait`](dma::TransferWait::try_wait) once the transfer is complete, or with
+/// the error of the start method or of `try_wait` if the transfer couldn't be started or failed,
+/// e.g. because the slave didn't acknowledge a byte.
+///
+/// Each transfer follows the bus contract of the corresponding blocking trait, e.g. a
+/// write-read transfer sends a repeated start between its two phases.
+///
+/// # Notes
+///
+/// - Only one transfer may be in progress at a time. Implementations are suggested to return an
+///   error from the start methods if a transfer is already in progress.
+pub mod dma {
+    use crate::blocking::i2c::{AddressMode, SevenBitAddress};
+
+    /// Buffers of a completed DMA transfer, or of one that couldn't be started or failed
+    #[derive(Debug)]
+    pub enum Buffers {
+        /// The bytes of a write
+        Write(&'static [u8]),
+        /// The buffer of a read, holding the bytes received from the slave
+        Read(&'static mut [u8]),
+        /// The bytes written and the buffer holding the bytes received from the slave
+        WriteRead(&'static [u8], &'static mut [u8]),
+    }
+
+    /// Start of a DMA write
+    pub trait WriteStart<A: AddressMode = SevenBitAddress> {
+        /// Error type
+        type Error;
+
+        /// Starts writing `bytes` to the slave with `address`
+        ///
+        /// On error, `bytes` is returned along with the error.
+        fn try_start_write(
+            &mut self,
+            address: A,
+            bytes: &'static [u8],
+        ) -> Result<(), (Self::Error, Buffers)>;
+    }
+
+    /// Start of a DMA read
+    pub trait ReadStart<A: AddressMode = SevenBitAddress> {
+        /// Error type
+        type Error;
+
+        /// Starts reading enough bytes from the slave with `address` to fill `buffer`
+        ///
+        /// On error, `buffer` is returned along with the error.
+        fn try_start_read(
+            &mut self,
+            address: A,
+            buffer: &'static mut [u8],
+        ) -> Result<(), (Self::Error, Buffers)>;
+    }
+
+    /// Start of a DMA write followed by a read
+    pub trait WriteReadStart<A: AddressMode = SevenBitAddress> {
+        /// Error type
+        type Error;
+
+        /// Starts writing `bytes` to the slave with `address`, then reading enough bytes to fill
+        /// `buffer`
+        ///
+        /// On error, `bytes` and `buffer` are returned along with the error.
+        fn try_start_write_read(
+            &mut self,
+            address: A,
+            bytes: &'static [u8],
+            buffer: &'static mut [u8],
+        ) -> Result<(), (Self::Error, Buffers)>;
+    }
+
+    /// Completion of a DMA transfer
+    pub trait TransferWait {
+        /// Error type
+        type Error;
+
+        /// Non-blockingly "waits" until the transfer in progress finishes, returning its buffers
+        ///
+        /// If the transfer failed, the buffers are returned along with the error.
+        fn try_wait(&mut self) -> nb::Result<Buffers, (Self::Error, Buffers)>;
+    }
+}
@@ -0,0 +1,206 @@
+//! Bridging with byte stream I/O
+//!
+//! Protocol crates are often written against byte stream traits instead of word oriented
+//! serial traits, and host tools talk to serial ports through them. The wrappers in this module
+//! convert between the two for 8-bit words:
+//!
+//! - [`IntoIo`] wraps an implementation of the serial traits of this crate and implements
+//!   [`std::io::Read`] and [`std::io::Write`] on top of it. Wrap it in a
+//!   [`BufReader`](std::io::BufReader) to get [`BufRead`](std::io::BufRead).
+//! - [`FromIo`] wraps a byte stream, e.g. a serial port or TCP socket on the host, and
+//!   implements the serial traits of this crate on top of it.
+//!
+//! Errors of the serial interface are converted to [`std::io::Error`]s of kind `Other`. Reads and
+//! writes of [`IntoIo`] block until the first byte is transferred rather than returning errors of
+//! kind `WouldBlock`, while [`FromIo`] reports the I/O errors of kind `WouldBlock` as
+//! `nb::Error::WouldBlock`.
+//!
+//! This module is only available when the `std` feature is enabled.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::io::IntoIo;
+//! use embedded_hal::loopback;
+//! use std::io::{BufRead, BufReader, Write};
+//!
+//! let mut port = IntoIo::new(loopback::Serial::<u8, 64>::new());
+//! port.write_all(b"$GPGGA,123519\n").unwrap();
+//!
+//! let mut sentence = String::new();
+//! BufReader::new(&mut port).read_line(&mut sentence).unwrap();
+//! assert_eq!(sentence, "$GPGGA,123519\n");
+//! ```
+
+use std::format;
+use std::io;
+
+use crate::blocking::serial::{read, write};
+use crate::serial::{Read, Write};
+
+// `io::Error::other` requires Rust 1.74
+fn io_error<E>(e: E) -> io::Error
+where
+    E: core::fmt::Debug,
+{
+    io::Error::new(io::ErrorKind::Other, format!("serial error: {:?}", e))
+}
+
+/// Byte stream over a serial interface
+///
+/// Reads block until at least one byte is received, then return the bytes that are available
+/// without blocking. Writes block until the first byte is accepted in the same way.
+pub struct IntoIo<S> {
+    serial: S,
+    /// Error that occurred after some bytes were already read, returned by the next read
+    error: Option<io::Error>,
+}
+
+impl<S> IntoIo<S> {
+    /// Creates a new byte stream over `serial`
+    pub fn new(serial: S) -> Self {
+        Self {
+            serial,
+            error: None,
+        }
+    }
+
+    /// Destroys the stream, returning the serial interface
+    pub fn destroy(self) -> S {
+        self.serial
+    }
+}
+
+impl<S> io::Read for IntoIo<S>
+where
+    S: Read<u8>,
+    S::Error: core::fmt::Debug,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        buf[0] = nb::block!(self.serial.try_read()).map_err(io_error)?;
+
+        let mut count = 1;
+        for byte in &mut buf[1..] {
+            match self.serial.try_read() {
+                Ok(b) => *byte = b,
+                Err(nb::Error::WouldBlock) => break,
+                Err(nb::Error::Other(e)) => {
+                    self.error = Some(io_error(e));
+                    break;
+                }
+            }
+            count += 1;
+        }
+
+        Ok(count)
+    }
+}
+
+impl<S> io::Write for IntoIo<S>
+where
+    S: Write<u8>,
+    S::Error: core::fmt::Debug,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let (first, rest) = match buf.split_first() {
+            Some(split) => split,
+            None => return Ok(0),
+        };
+
+        nb::block!(self.serial.try_write(*first)).map_err(io_error)?;
+
+        let mut count = 1;
+        for byte in rest {
+            match self.serial.try_write(*byte) {
+                Ok(()) => count += 1,
+                Err(nb::Error::WouldBlock) => break,
+                // the error will be returned again by the next write if it persists
+                Err(nb::Error::Other(_)) => break,
+            }
+        }
+
+        Ok(count)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        nb::block!(self.serial.try_flush()).map_err(io_error)
+    }
+}
+
+/// Serial interface over a byte stream
+///
+/// The I/O errors of kind `WouldBlock` and `Interrupted` are reported as `WouldBlock`. The end
+/// of the stream is reported as an error of kind `UnexpectedEof` by reads and `WriteZero` by
+/// writes.
+pub struct FromIo<T> {
+    io: T,
+}
+
+impl<T> FromIo<T> {
+    /// Creates a new serial interface over `io`
+    pub fn new(io: T) -> Self {
+        Self { io }
+    }
+
+    /// Returns a reference to the byte stream
+    pub fn inner(&self) -> &T {
+        &self.io
+    }
+
+    /// Destroys the serial interface, returning the byte stream
+    pub fn destroy(self) -> T {
+        self.io
+    }
+}
+
+fn nb_error(e: io::Error) -> nb::Error<io::Error> {
+    match e.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::Interrupted => nb::Error::WouldBlock,
+        _ => nb::Error::Other(e),
+    }
+}
+
+impl<T> Read<u8> for FromIo<T>
+where
+    T: io::Read,
+{
+    type Error = io::Error;
+
+    fn try_read(&mut self) -> nb::Result<u8, Self::Error> {
+        let mut byte = [0];
+        match self.io.read(&mut byte).map_err(nb_error)? {
+            0 => Err(nb::Error::Other(io::ErrorKind::UnexpectedEof.into())),
+            _ => Ok(byte[0]),
+        }
+    }
+}
+
+impl<T> Write<u8> for FromIo<T>
+where
+    T: io::Write,
+{
+    type Error = io::Error;
+
+    fn try_write(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+        match self.io.write(&[word]).map_err(nb_error)? {
+            0 => Err(nb::Error::Other(io::ErrorKind::WriteZero.into())),
+            _ => Ok(()),
+        }
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        self.io.flush().map_err(nb_error)
+    }
+}
+
+impl<T> read::Default<u8> for FromIo<T> where T: io::Read {}
+
+impl<T> write::Default<u8> for FromIo<T> where T: io::Write {}
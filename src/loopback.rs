@@ -0,0 +1,255 @@
+//! Loopback implementations for testing without hardware
+//!
+//! The types in this module behave as if their outputs were wired to their inputs, which is
+//! useful for smoke tests of generic driver code and for examples.
+
+use crate::blocking::spi::{Flush, Operation, Transactional, Transfer, Write, WriteIter};
+use crate::{blocking, serial};
+
+/// SPI bus with MOSI wired to MISO
+///
+/// Every word sent is received back unchanged by transfers. A transform can be applied to the
+/// received words with [`with_transform`](Spi::with_transform), e.g. to emulate a device that
+/// inverts or offsets the data.
+///
+/// The blocking SPI traits are implemented for 8, 16 and 32-bit words; with a transform, for the
+/// word types the transform accepts.
+///
+/// ```
+/// use embedded_hal::blocking::spi::Transfer;
+/// use embedded_hal::loopback;
+///
+/// let mut spi = loopback::Spi::new();
+/// assert_eq!(spi.try_transfer(&mut [1u8, 2, 3]).unwrap(), &[1, 2, 3]);
+///
+/// let mut spi = loopback::Spi::with_transform(|word: u8| !word);
+/// assert_eq!(spi.try_transfer(&mut [0x00, 0xF0]).unwrap(), &[0xFF, 0x0F]);
+/// ```
+pub struct Spi<F = ()> {
+    transform: F,
+}
+
+impl Spi {
+    /// Creates a loopback bus returning the sent words unchanged
+    pub fn new() -> Self {
+        Spi { transform: () }
+    }
+}
+
+impl Default for Spi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F> Spi<F> {
+    /// Creates a loopback bus passing each sent word through `transform`
+    pub fn with_transform(transform: F) -> Self {
+        Spi { transform }
+    }
+}
+
+macro_rules! impl_loopback_spi {
+    ($W:ty, [$($gen:tt)*] $ty:ty, [$($bound:tt)*], |$s:ident, $words:ident| $transform:block) => {
+        impl<$($gen)*> Transfer<$W> for $ty
+        where
+            $($bound)*
+        {
+            type Error = core::convert::Infallible;
+
+            fn try_transfer<'w>(&mut self, words: &'w mut [$W]) -> Result<&'w [$W], Self::Error> {
+                let ($s, $words) = (self, words);
+                $transform
+
+                Ok($words)
+            }
+        }
+
+        impl<$($gen)*> Write<$W> for $ty
+        where
+            $($bound)*
+        {
+            type Error = core::convert::Infallible;
+
+            fn try_write(&mut self, _words: &[$W]) -> Result<(), Self::Error> {
+                Ok(())
+            }
+        }
+
+        impl<$($gen)*> WriteIter<$W> for $ty
+        where
+            $($bound)*
+        {
+            type Error = core::convert::Infallible;
+
+            fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+            where
+                WI: IntoIterator<Item = $W>,
+            {
+                words.into_iter().for_each(drop);
+
+                Ok(())
+            }
+        }
+
+        impl<$($gen)*> Transactional<$W> for $ty
+        where
+            $($bound)*
+        {
+            type Error = core::convert::Infallible;
+
+            fn try_exec<'a>(
+                &mut self,
+                operations: &mut [Operation<'a, $W>],
+            ) -> Result<(), Self::Error> {
+                for op in operations {
+                    match op {
+                        Operation::Read(r) => {
+                            r.fill(0);
+                            self.try_transfer(r)?;
+                        }
+                        Operation::Write(_) | Operation::DelayUs(_) => {}
+                        Operation::Transfer(r, w) => {
+                            let n = r.len().min(w.len());
+                            r[..n].copy_from_slice(&w[..n]);
+                            r[n..].fill(0);
+                            self.try_transfer(r)?;
+                        }
+                        Operation::TransferInPlace(t) => {
+                            self.try_transfer(t)?;
+                        }
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+macro_rules! impl_loopback_spi_words {
+    ($($W:ty),*) => {
+        $(
+            impl<F> Flush<$W> for Spi<F> {
+                type Error = core::convert::Infallible;
+
+                fn try_flush(&mut self) -> Result<(), Self::Error> {
+                    Ok(())
+                }
+            }
+
+            impl_loopback_spi!($W, [] Spi<()>, [], |_s, words| {});
+            impl_loopback_spi!($W, [F] Spi<F>, [F: FnMut($W) -> $W], |s, words| {
+                for word in words.iter_mut() {
+                    *word = (s.transform)(*word);
+                }
+            });
+        )*
+    };
+}
+
+impl_loopback_spi_words!(u8, u16, u32);
+
+/// Serial interface with TX wired to RX
+///
+/// Written words are stored in an internal ring buffer of `N` words and read back in order.
+/// Reads return `WouldBlock` while the buffer is empty and writes while it is full, so blocking
+/// writes of more than `N` words never complete unless the words are read concurrently.
+///
+/// ```
+/// use embedded_hal::blocking::serial::{Read, Write};
+/// use embedded_hal::loopback;
+///
+/// let mut serial = loopback::Serial::<u8, 16>::new();
+/// serial.try_bwrite_all(b"ping").unwrap();
+///
+/// let mut echo = [0; 4];
+/// serial.try_read_exact(&mut echo).unwrap();
+/// assert_eq!(&echo, b"ping");
+/// ```
+pub struct Serial<W, const N: usize> {
+    buffer: [W; N],
+    /// Index of the next word to read
+    head: usize,
+    len: usize,
+}
+
+impl<W, const N: usize> Serial<W, N>
+where
+    W: Copy + Default,
+{
+    /// Creates a loopback interface with an empty buffer
+    pub fn new() -> Self {
+        Serial {
+            buffer: [W::default(); N],
+            head: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<W, const N: usize> Default for Serial<W, N>
+where
+    W: Copy + Default,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W, const N: usize> Serial<W, N> {
+    /// Returns the number of words written but not read yet
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if all the written words have been read
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<W, const N: usize> serial::Read<W> for Serial<W, N>
+where
+    W: Copy,
+{
+    type Error = core::convert::Infallible;
+
+    fn try_read(&mut self) -> nb::Result<W, Self::Error> {
+        if self.len == 0 {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        let word = self.buffer[self.head];
+        self.head = (self.head + 1) % N;
+        self.len -= 1;
+
+        Ok(word)
+    }
+}
+
+impl<W, const N: usize> serial::Write<W> for Serial<W, N>
+where
+    W: Copy,
+{
+    type Error = core::convert::Infallible;
+
+    fn try_write(&mut self, word: W) -> nb::Result<(), Self::Error> {
+        if self.len == N {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        self.buffer[(self.head + self.len) % N] = word;
+        self.len += 1;
+
+        Ok(())
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<W, const N: usize> blocking::serial::read::Default<W> for Serial<W, N> where W: Copy {}
+
+impl<W, const N: usize> blocking::serial::write::Default<W> for Serial<W, N> where W: Copy {}
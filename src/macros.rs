@@ -0,0 +1,127 @@
+//! Internal macros
+
+/// Declares the error enum of a wrapper, with its `Display` and `std::error::Error`
+/// implementations and optionally the error trait of its interface
+///
+/// Each variant is mapped to a kind, either `forward`ing the kind of the wrapped error, a kind
+/// variant carrying the wrapped value with `Kind(..)`, or a plain `Kind`. Variants are displayed
+/// as their message, followed by the wrapped value if any.
+///
+/// ```text
+/// wrapper_error! {
+///     /// Software PWM error
+///     #[derive(Clone, Debug, PartialEq)]
+///     pub enum SoftPwmError<PinError, TimerError> {
+///         /// Underlying pin error
+///         Pin(PinError) => forward, "pin error",
+///         /// Underlying timer error
+///         Timer(TimerError) => Other, "timer error",
+///     }
+///
+///     impl super::Error => super::ErrorKind where PinError: super::Error
+/// }
+/// ```
+macro_rules! wrapper_error {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident<$($param:ident $(= $default:ty)?),+> {
+            $(
+                $(#[$vattr:meta])*
+                $variant:ident $(($inner:ty))? => $vkind:ident $(($($vargs:tt)*))?, $msg:literal,
+            )+
+        }
+
+        impl $error:path => $kind:ty $(where $($bp:ident: $bt:path),+)?
+    ) => {
+        wrapper_error! {
+            $(#[$attr])*
+            pub enum $name<$($param $(= $default)?),+> {
+                $(
+                    $(#[$vattr])*
+                    $variant $(($inner))? => $vkind $(($($vargs)*))?, $msg,
+                )+
+            }
+        }
+
+        impl<$($param),+> $error for $name<$($param),+>
+        where
+            $($param: core::fmt::Debug,)+
+            $($($bp: $bt,)+)?
+        {
+            #[allow(unused_variables)]
+            fn kind(&self) -> $kind {
+                match self {
+                    $(
+                        wrapper_error!(@pattern $name::$variant, e $(, $inner)?) => {
+                            wrapper_error!(@kind $kind, e, $vkind $(($($vargs)*))?)
+                        }
+                    )+
+                }
+            }
+        }
+    };
+
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident<$($param:ident $(= $default:ty)?),+> {
+            $(
+                $(#[$vattr:meta])*
+                $variant:ident $(($inner:ty))? => $vkind:ident $(($($vargs:tt)*))?, $msg:literal,
+            )+
+        }
+    ) => {
+        $(#[$attr])*
+        pub enum $name<$($param $(= $default)?),+> {
+            $(
+                $(#[$vattr])*
+                $variant $(($inner))?,
+            )+
+        }
+
+        impl<$($param),+> core::fmt::Display for $name<$($param),+>
+        where
+            $($param: core::fmt::Debug,)+
+        {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match self {
+                    $(
+                        wrapper_error!(@pattern $name::$variant, e $(, $inner)?) => {
+                            wrapper_error!(@display f, $msg, e $(, $inner)?)
+                        }
+                    )+
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<$($param),+> std::error::Error for $name<$($param),+>
+        where
+            $($param: core::fmt::Debug,)+
+        {
+        }
+    };
+
+    (@pattern $name:ident::$variant:ident, $e:ident, $inner:ty) => {
+        $name::$variant($e)
+    };
+    (@pattern $name:ident::$variant:ident, $e:ident) => {
+        $name::$variant
+    };
+
+    (@kind $kind:ty, $e:ident, forward) => {
+        $e.kind()
+    };
+    (@kind $kind:ty, $e:ident, $vkind:ident(..)) => {
+        <$kind>::$vkind(*$e)
+    };
+    (@kind $kind:ty, $e:ident, $vkind:ident) => {
+        <$kind>::$vkind
+    };
+
+    (@display $f:ident, $msg:literal, $e:ident, $inner:ty) => {
+        write!($f, concat!($msg, ": {:?}"), $e)
+    };
+    (@display $f:ident, $msg:literal, $e:ident) => {
+        write!($f, $msg)
+    };
+}
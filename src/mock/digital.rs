@@ -0,0 +1,359 @@
+//! Digital pin mock
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::digital::{InputPin, OutputPin, PinState};
+//! use embedded_hal::mock::digital::{Mock, Transaction};
+//!
+//! let expectations = [
+//!     Transaction::set(PinState::Low),
+//!     Transaction::get(PinState::High),
+//!     Transaction::set(PinState::High),
+//! ];
+//! let mut pin = Mock::new(&expectations);
+//!
+//! pin.try_set_low().unwrap();
+//! assert!(pin.try_is_high().unwrap());
+//! pin.try_set_high().unwrap();
+//!
+//! pin.done();
+//! ```
+
+use core::cell::{Cell, RefCell};
+use core::convert::Infallible;
+
+use crate::digital::{InputPin, OutputPin, PinState, StatefulOutputPin, ToggleableOutputPin};
+
+/// Expected pin operation
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Transaction {
+    /// Driving the output to the contained state
+    Set(PinState),
+    /// Reading the input, responding with the contained level
+    Get(PinState),
+    /// Reading the output state, responding with the contained state
+    GetState(PinState),
+    /// Toggling the output
+    Toggle,
+}
+
+impl Transaction {
+    /// Expects the output to be driven to `state`
+    pub fn set(state: PinState) -> Self {
+        Transaction::Set(state)
+    }
+
+    /// Expects the input to be read, responding with `level`
+    pub fn get(level: PinState) -> Self {
+        Transaction::Get(level)
+    }
+
+    /// Expects the output state to be read, responding with `state`
+    pub fn get_state(state: PinState) -> Self {
+        Transaction::GetState(state)
+    }
+
+    /// Expects the output to be toggled
+    pub fn toggle() -> Self {
+        Transaction::Toggle
+    }
+}
+
+/// Mock digital pin
+///
+/// Implements the input, output, stateful output and toggleable output traits, panicking if a
+/// call does not match the next expected [`Transaction`]. Both `try_is_high` and `try_is_low`
+/// match a `Get` expectation, and both `try_is_set_high` and `try_is_set_low` match a
+/// `GetState` expectation.
+pub struct Mock<'a> {
+    expectations: &'a [Transaction],
+    index: Cell<usize>,
+}
+
+impl<'a> Mock<'a> {
+    /// Creates a new mock expecting the provided operations in order
+    pub fn new(expectations: &'a [Transaction]) -> Self {
+        Self {
+            expectations,
+            index: Cell::new(0),
+        }
+    }
+
+    /// Asserts that all the expected operations have been executed
+    pub fn done(&mut self) {
+        assert_eq!(
+            self.index.get(),
+            self.expectations.len(),
+            "not all expected pin operations were executed"
+        );
+    }
+
+    fn next(&self) -> Transaction {
+        let index = self.index.get();
+        let t = *self
+            .expectations
+            .get(index)
+            .unwrap_or_else(|| panic!("unexpected pin operation, all expectations consumed"));
+        self.index.set(index + 1);
+        t
+    }
+
+    fn set(&mut self, state: PinState) {
+        match self.next() {
+            Transaction::Set(expected) => assert_eq!(state, expected, "pin state mismatch"),
+            t => panic!("expected {:?}, got pin set to {:?}", t, state),
+        }
+    }
+
+    fn get(&self) -> PinState {
+        match self.next() {
+            Transaction::Get(level) => level,
+            t => panic!("expected {:?}, got pin read", t),
+        }
+    }
+
+    fn get_state(&self) -> PinState {
+        match self.next() {
+            Transaction::GetState(state) => state,
+            t => panic!("expected {:?}, got pin output state read", t),
+        }
+    }
+}
+
+impl<'a> OutputPin for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+        self.set(PinState::Low);
+
+        Ok(())
+    }
+
+    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+        self.set(PinState::High);
+
+        Ok(())
+    }
+}
+
+impl<'a> StatefulOutputPin for Mock<'a> {
+    fn try_is_set_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.get_state() == PinState::High)
+    }
+
+    fn try_is_set_low(&self) -> Result<bool, Self::Error> {
+        Ok(self.get_state() == PinState::Low)
+    }
+}
+
+impl<'a> ToggleableOutputPin for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_toggle(&mut self) -> Result<(), Self::Error> {
+        match self.next() {
+            Transaction::Toggle => {}
+            t => panic!("expected {:?}, got pin toggle", t),
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> InputPin for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_is_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.get() == PinState::High)
+    }
+
+    fn try_is_low(&self) -> Result<bool, Self::Error> {
+        Ok(self.get() == PinState::Low)
+    }
+}
+
+/// Pin operation recorded by a [`RecordingPin`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Record {
+    /// The operation, as the expectation a [`Mock`] would need to match it
+    pub transaction: Transaction,
+    /// The time of the operation, if the pin has a clock
+    pub timestamp: Option<u64>,
+}
+
+impl Default for Record {
+    /// Placeholder record, for initializing buffers
+    fn default() -> Self {
+        Record {
+            transaction: Transaction::Toggle,
+            timestamp: None,
+        }
+    }
+}
+
+/// Pin wrapper recording every operation into a buffer
+///
+/// Unlike [`Mock`], the operations are forwarded to the wrapped pin, and checked once the
+/// driver is done. Only the operations that succeed are recorded; the recorder panics once the
+/// buffer is full.
+///
+/// ```
+/// use embedded_hal::digital::dummy::DummyPin;
+/// use embedded_hal::digital::{OutputPin, PinState};
+/// use embedded_hal::mock::digital::{Record, RecordingPin, Transaction};
+/// # use core::cell::Cell;
+/// # let now = Cell::new(0);
+///
+/// let mut buffer = [Record::default(); 4];
+/// // timestamps in microseconds from the test clock
+/// let clock = || {
+///     now.set(now.get() + 10);
+///     now.get()
+/// };
+/// let mut reset = RecordingPin::with_clock(DummyPin::new_low(), &mut buffer, clock);
+///
+/// // a driver pulsing its reset pin
+/// reset.try_set_low().unwrap();
+/// reset.try_set_high().unwrap();
+///
+/// let records = reset.records();
+/// assert_eq!(records[0].transaction, Transaction::set(PinState::Low));
+/// assert_eq!(records[1].transaction, Transaction::set(PinState::High));
+/// assert_eq!(records[1].timestamp.unwrap() - records[0].timestamp.unwrap(), 10);
+/// ```
+pub struct RecordingPin<'a, P, Clock> {
+    pin: P,
+    buffer: RefCell<&'a mut [Record]>,
+    len: Cell<usize>,
+    clock: Option<Clock>,
+}
+
+impl<'a, P> RecordingPin<'a, P, fn() -> u64> {
+    /// Wraps `pin`, recording its operations into `buffer` without timestamps
+    pub fn new(pin: P, buffer: &'a mut [Record]) -> Self {
+        RecordingPin {
+            pin,
+            buffer: RefCell::new(buffer),
+            len: Cell::new(0),
+            clock: None,
+        }
+    }
+}
+
+impl<'a, P, Clock> RecordingPin<'a, P, Clock>
+where
+    Clock: Fn() -> u64,
+{
+    /// Wraps `pin`, recording its operations into `buffer` with timestamps from `clock`
+    pub fn with_clock(pin: P, buffer: &'a mut [Record], clock: Clock) -> Self {
+        RecordingPin {
+            pin,
+            buffer: RefCell::new(buffer),
+            len: Cell::new(0),
+            clock: Some(clock),
+        }
+    }
+
+    /// Returns the recorded operations
+    pub fn records(&mut self) -> &[Record] {
+        &self.buffer.get_mut()[..self.len.get()]
+    }
+
+    /// Destroys the recorder, returning the pin and the recorded operations
+    pub fn destroy(self) -> (P, &'a [Record]) {
+        let buffer = self.buffer.into_inner();
+        (self.pin, &buffer[..self.len.get()])
+    }
+
+    fn record(&self, transaction: Transaction) {
+        let len = self.len.get();
+        let mut buffer = self.buffer.borrow_mut();
+        let record = buffer
+            .get_mut(len)
+            .unwrap_or_else(|| panic!("pin recording buffer full"));
+        *record = Record {
+            transaction,
+            timestamp: self.clock.as_ref().map(|clock| clock()),
+        };
+        self.len.set(len + 1);
+    }
+}
+
+impl<'a, P, Clock> OutputPin for RecordingPin<'a, P, Clock>
+where
+    P: OutputPin,
+    Clock: Fn() -> u64,
+{
+    type Error = P::Error;
+
+    fn try_set_low(&mut self) -> Result<(), Self::Error> {
+        self.pin.try_set_low()?;
+        self.record(Transaction::Set(PinState::Low));
+
+        Ok(())
+    }
+
+    fn try_set_high(&mut self) -> Result<(), Self::Error> {
+        self.pin.try_set_high()?;
+        self.record(Transaction::Set(PinState::High));
+
+        Ok(())
+    }
+}
+
+impl<'a, P, Clock> StatefulOutputPin for RecordingPin<'a, P, Clock>
+where
+    P: StatefulOutputPin,
+    Clock: Fn() -> u64,
+{
+    fn try_is_set_high(&self) -> Result<bool, Self::Error> {
+        let high = self.pin.try_is_set_high()?;
+        self.record(Transaction::GetState(PinState::from(high)));
+
+        Ok(high)
+    }
+
+    fn try_is_set_low(&self) -> Result<bool, Self::Error> {
+        let low = self.pin.try_is_set_low()?;
+        self.record(Transaction::GetState(PinState::from(!low)));
+
+        Ok(low)
+    }
+}
+
+impl<'a, P, Clock> ToggleableOutputPin for RecordingPin<'a, P, Clock>
+where
+    P: ToggleableOutputPin,
+    Clock: Fn() -> u64,
+{
+    type Error = P::Error;
+
+    fn try_toggle(&mut self) -> Result<(), Self::Error> {
+        self.pin.try_toggle()?;
+        self.record(Transaction::Toggle);
+
+        Ok(())
+    }
+}
+
+impl<'a, P, Clock> InputPin for RecordingPin<'a, P, Clock>
+where
+    P: InputPin,
+    Clock: Fn() -> u64,
+{
+    type Error = P::Error;
+
+    fn try_is_high(&self) -> Result<bool, Self::Error> {
+        let high = self.pin.try_is_high()?;
+        self.record(Transaction::Get(PinState::from(high)));
+
+        Ok(high)
+    }
+
+    fn try_is_low(&self) -> Result<bool, Self::Error> {
+        let low = self.pin.try_is_low()?;
+        self.record(Transaction::Get(PinState::from(!low)));
+
+        Ok(low)
+    }
+}
@@ -0,0 +1,232 @@
+//! I2C mock
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::i2c::{Write, WriteRead};
+//! use embedded_hal::mock::i2c::{Mock, Transaction};
+//!
+//! let expectations = [
+//!     Transaction::write(0x48, &[0x01, 0x60]),
+//!     Transaction::write_read(0x48, &[0x00], &[0x19, 0x80]),
+//! ];
+//! let mut i2c = Mock::new(&expectations);
+//!
+//! i2c.try_write(0x48, &[0x01, 0x60]).unwrap();
+//!
+//! let mut temperature = [0; 2];
+//! i2c.try_write_read(0x48, &[0x00], &mut temperature).unwrap();
+//! assert_eq!(temperature, [0x19, 0x80]);
+//!
+//! i2c.done();
+//! ```
+
+use core::convert::Infallible;
+
+use crate::blocking::i2c::{
+    Operation, Read, Transactional, Write, WriteIter, WriteIterRead, WriteRead,
+};
+
+/// Expected I2C transaction
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Transaction<'a> {
+    /// Read from the address, responding with the contained bytes
+    Read(u8, &'a [u8]),
+    /// Write of the contained bytes to the address, through either `Write` or `WriteIter`
+    Write(u8, &'a [u8]),
+    /// Write of the first bytes to the address, then read responding with the second bytes,
+    /// through either `WriteRead` or `WriteIterRead`
+    WriteRead(u8, &'a [u8], &'a [u8]),
+}
+
+impl<'a> Transaction<'a> {
+    /// Expects a read from `address`, responding with `response`
+    pub fn read(address: u8, response: &'a [u8]) -> Self {
+        Transaction::Read(address, response)
+    }
+
+    /// Expects the bytes `expected` to be written to `address`
+    pub fn write(address: u8, expected: &'a [u8]) -> Self {
+        Transaction::Write(address, expected)
+    }
+
+    /// Expects the bytes `expected` to be written to `address`, followed by a read responding
+    /// with `response`
+    pub fn write_read(address: u8, expected: &'a [u8], response: &'a [u8]) -> Self {
+        Transaction::WriteRead(address, expected, response)
+    }
+}
+
+/// Mock I2C bus
+///
+/// Implements the blocking I2C traits for 7-bit addresses, panicking if a call does not match
+/// the next expected [`Transaction`]. Each operation passed to `Transactional` is matched
+/// against a separate `Read` or `Write` expectation.
+pub struct Mock<'a> {
+    expectations: &'a [Transaction<'a>],
+    index: usize,
+}
+
+impl<'a> Mock<'a> {
+    /// Creates a new mock expecting the provided transactions in order
+    pub fn new(expectations: &'a [Transaction<'a>]) -> Self {
+        Self {
+            expectations,
+            index: 0,
+        }
+    }
+
+    /// Asserts that all the expected transactions have been executed
+    pub fn done(&mut self) {
+        assert_eq!(
+            self.index,
+            self.expectations.len(),
+            "not all expected I2C transactions were executed"
+        );
+    }
+
+    fn next(&mut self) -> Transaction<'a> {
+        let t = *self
+            .expectations
+            .get(self.index)
+            .unwrap_or_else(|| panic!("unexpected I2C transaction, all expectations consumed"));
+        self.index += 1;
+        t
+    }
+
+    fn read(&mut self, address: u8, buffer: &mut [u8]) {
+        match self.next() {
+            Transaction::Read(expected, response) => {
+                assert_eq!(address, expected, "I2C read address mismatch");
+                assert_eq!(buffer.len(), response.len(), "I2C read length mismatch");
+                buffer.copy_from_slice(response);
+            }
+            t => panic!("expected {:?}, got I2C read", t),
+        }
+    }
+
+    fn write(&mut self, address: u8, bytes: &[u8]) {
+        match self.next() {
+            Transaction::Write(expected_address, expected) => {
+                assert_eq!(address, expected_address, "I2C write address mismatch");
+                assert_eq!(bytes, expected, "I2C write data mismatch");
+            }
+            t => panic!("expected {:?}, got I2C write", t),
+        }
+    }
+}
+
+/// Checks that `bytes` yields exactly `expected`
+fn check_iter<B>(bytes: B, expected: &[u8])
+where
+    B: IntoIterator<Item = u8>,
+{
+    let mut count = 0;
+    for byte in bytes {
+        assert_eq!(
+            Some(&byte),
+            expected.get(count),
+            "I2C write data mismatch at byte {}",
+            count
+        );
+        count += 1;
+    }
+    assert_eq!(count, expected.len(), "I2C write data too short");
+}
+
+impl<'a> Read for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_read(&mut self, address: u8, buffer: &mut [u8]) -> Result<(), Self::Error> {
+        self.read(address, buffer);
+
+        Ok(())
+    }
+}
+
+impl<'a> Write for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_write(&mut self, address: u8, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.write(address, bytes);
+
+        Ok(())
+    }
+}
+
+impl<'a> WriteIter for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_write_iter<B>(&mut self, address: u8, bytes: B) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        match self.next() {
+            Transaction::Write(expected_address, expected) => {
+                assert_eq!(address, expected_address, "I2C write address mismatch");
+                check_iter(bytes, expected);
+            }
+            t => panic!("expected {:?}, got I2C write", t),
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> WriteRead for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_write_read(
+        &mut self,
+        address: u8,
+        bytes: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        self.try_write_iter_read(address, bytes.iter().copied(), buffer)
+    }
+}
+
+impl<'a> WriteIterRead for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_write_iter_read<B>(
+        &mut self,
+        address: u8,
+        bytes: B,
+        buffer: &mut [u8],
+    ) -> Result<(), Self::Error>
+    where
+        B: IntoIterator<Item = u8>,
+    {
+        match self.next() {
+            Transaction::WriteRead(expected_address, expected, response) => {
+                assert_eq!(address, expected_address, "I2C write read address mismatch");
+                check_iter(bytes, expected);
+                assert_eq!(buffer.len(), response.len(), "I2C read length mismatch");
+                buffer.copy_from_slice(response);
+            }
+            t => panic!("expected {:?}, got I2C write read", t),
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Transactional for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_exec<'o>(
+        &mut self,
+        address: u8,
+        operations: &mut [Operation<'o>],
+    ) -> Result<(), Self::Error> {
+        for op in operations {
+            match op {
+                Operation::Read(buffer) => self.read(address, buffer),
+                Operation::Write(bytes) => self.write(address, bytes),
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+//! Mock implementations for testing drivers on the host
+//!
+//! The mocks in this module are constructed from a list of expected transactions. Each call to
+//! one of the implemented traits is checked against the next expectation and panics on a
+//! mismatch, so they are meant to be used from `#[test]` functions. Call `done` at the end of a
+//! test to check that all the expectations have been consumed.
+//!
+//! [`digital::RecordingPin`] instead records the operations on a pin, to be checked afterwards.
+//!
+//! This module is only available when the `mock` feature is enabled.
+
+pub mod digital;
+pub mod i2c;
+pub mod serial;
+pub mod spi;
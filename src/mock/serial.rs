@@ -0,0 +1,181 @@
+//! Serial mock
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::serial::{Read, Write};
+//! use embedded_hal::mock::serial::{Mock, Transaction};
+//!
+//! let expectations = [
+//!     Transaction::write(b"AT\r\n"),
+//!     Transaction::flush(),
+//!     // the modem takes a while to answer
+//!     Transaction::would_block(),
+//!     Transaction::read(b"OK\r\n"),
+//! ];
+//! let mut modem = Mock::new(&expectations);
+//!
+//! modem.try_bwrite_all(b"AT\r\n").unwrap();
+//! modem.try_bflush().unwrap();
+//!
+//! let mut response = [0; 4];
+//! modem.try_read_exact(&mut response).unwrap();
+//! assert_eq!(&response, b"OK\r\n");
+//!
+//! modem.done();
+//! ```
+
+use core::convert::Infallible;
+
+use crate::blocking::serial::{read, write};
+use crate::serial::{Read, Write};
+
+/// Expected serial transaction
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Transaction<'a> {
+    /// Reads of the contained words, one per call
+    Read(&'a [u8]),
+    /// Writes of the contained words, one per call
+    Write(&'a [u8]),
+    /// Flush
+    Flush,
+    /// `WouldBlock` returned by the next read, write or flush
+    WouldBlock,
+}
+
+impl<'a> Transaction<'a> {
+    /// Expects the words of `response` to be read
+    ///
+    /// `response` can't be empty.
+    pub fn read(response: &'a [u8]) -> Self {
+        assert!(!response.is_empty(), "empty serial read expectation");
+        Transaction::Read(response)
+    }
+
+    /// Expects the words `expected` to be written
+    ///
+    /// `expected` can't be empty.
+    pub fn write(expected: &'a [u8]) -> Self {
+        assert!(!expected.is_empty(), "empty serial write expectation");
+        Transaction::Write(expected)
+    }
+
+    /// Expects a flush
+    pub fn flush() -> Self {
+        Transaction::Flush
+    }
+
+    /// Returns `WouldBlock` from the next call, which is then expected to be retried
+    pub fn would_block() -> Self {
+        Transaction::WouldBlock
+    }
+}
+
+/// Mock serial interface
+///
+/// Implements the non-blocking serial traits for 8-bit words, and the blocking ones through
+/// their default implementations, panicking if a call does not match the next expected
+/// [`Transaction`]. Each read or write call consumes one word of a `Read` or `Write`
+/// expectation.
+pub struct Mock<'a> {
+    expectations: &'a [Transaction<'a>],
+    index: usize,
+    /// Position within the words of the current expectation
+    offset: usize,
+}
+
+impl<'a> Mock<'a> {
+    /// Creates a new mock expecting the provided transactions in order
+    pub fn new(expectations: &'a [Transaction<'a>]) -> Self {
+        Self {
+            expectations,
+            index: 0,
+            offset: 0,
+        }
+    }
+
+    /// Asserts that all the expected transactions have been executed
+    pub fn done(&mut self) {
+        assert_eq!(
+            self.index,
+            self.expectations.len(),
+            "not all expected serial transactions were executed"
+        );
+    }
+
+    fn current(&self) -> Transaction<'a> {
+        *self
+            .expectations
+            .get(self.index)
+            .unwrap_or_else(|| panic!("unexpected serial transaction, all expectations consumed"))
+    }
+
+    /// Moves to the next word of the current expectation, whose words are `len` long
+    fn advance(&mut self, len: usize) {
+        self.offset += 1;
+        if self.offset == len {
+            self.index += 1;
+            self.offset = 0;
+        }
+    }
+}
+
+impl<'a> Read<u8> for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_read(&mut self) -> nb::Result<u8, Self::Error> {
+        match self.current() {
+            Transaction::Read(response) => {
+                let word = response[self.offset];
+                self.advance(response.len());
+                Ok(word)
+            }
+            Transaction::WouldBlock => {
+                self.advance(1);
+                Err(nb::Error::WouldBlock)
+            }
+            t => panic!("expected {:?}, got serial read", t),
+        }
+    }
+}
+
+impl<'a> Write<u8> for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_write(&mut self, word: u8) -> nb::Result<(), Self::Error> {
+        match self.current() {
+            Transaction::Write(expected) => {
+                assert_eq!(
+                    word, expected[self.offset],
+                    "serial write data mismatch at word {}",
+                    self.offset
+                );
+                self.advance(expected.len());
+                Ok(())
+            }
+            Transaction::WouldBlock => {
+                self.advance(1);
+                Err(nb::Error::WouldBlock)
+            }
+            t => panic!("expected {:?}, got serial write", t),
+        }
+    }
+
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+        match self.current() {
+            Transaction::Flush => {
+                self.advance(1);
+                Ok(())
+            }
+            Transaction::WouldBlock => {
+                self.advance(1);
+                Err(nb::Error::WouldBlock)
+            }
+            t => panic!("expected {:?}, got serial flush", t),
+        }
+    }
+}
+
+impl<'a> read::Default<u8> for Mock<'a> {}
+
+impl<'a> write::Default<u8> for Mock<'a> {}
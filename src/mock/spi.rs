@@ -0,0 +1,156 @@
+//! SPI mock
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::blocking::spi::{Transfer, Write};
+//! use embedded_hal::mock::spi::{Mock, Transaction};
+//!
+//! let expectations = [
+//!     Transaction::write(&[0x06]),
+//!     Transaction::transfer(&[0x05, 0x00], &[0xFF, 0x02]),
+//! ];
+//! let mut spi = Mock::new(&expectations);
+//!
+//! spi.try_write(&[0x06]).unwrap();
+//!
+//! let mut buf = [0x05, 0x00];
+//! assert_eq!(spi.try_transfer(&mut buf).unwrap(), &[0xFF, 0x02]);
+//!
+//! spi.done();
+//! ```
+
+use core::convert::Infallible;
+
+use crate::blocking::spi::{Flush, Transfer, Write, WriteIter};
+
+/// Expected SPI transaction
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Transaction<'a> {
+    /// Write of the contained words, through either `Write` or `WriteIter`
+    Write(&'a [u8]),
+    /// Transfer of the first words, responding with the second words
+    Transfer(&'a [u8], &'a [u8]),
+}
+
+impl<'a> Transaction<'a> {
+    /// Expects the words `expected` to be written
+    pub fn write(expected: &'a [u8]) -> Self {
+        Transaction::Write(expected)
+    }
+
+    /// Expects the words `expected` to be transferred, responding with `response`
+    ///
+    /// `expected` and `response` must have the same length.
+    pub fn transfer(expected: &'a [u8], response: &'a [u8]) -> Self {
+        assert_eq!(
+            expected.len(),
+            response.len(),
+            "transfer expectation and response lengths differ"
+        );
+        Transaction::Transfer(expected, response)
+    }
+}
+
+/// Mock SPI bus
+///
+/// Implements the blocking SPI traits for 8-bit words, panicking if a call does not match the
+/// next expected [`Transaction`]. Flushes always succeed and aren't matched against the
+/// expectations, so the mock can be used with chip select wrappers.
+pub struct Mock<'a> {
+    expectations: &'a [Transaction<'a>],
+    index: usize,
+}
+
+impl<'a> Mock<'a> {
+    /// Creates a new mock expecting the provided transactions in order
+    pub fn new(expectations: &'a [Transaction<'a>]) -> Self {
+        Self {
+            expectations,
+            index: 0,
+        }
+    }
+
+    /// Asserts that all the expected transactions have been executed
+    pub fn done(&mut self) {
+        assert_eq!(
+            self.index,
+            self.expectations.len(),
+            "not all expected SPI transactions were executed"
+        );
+    }
+
+    fn next(&mut self) -> Transaction<'a> {
+        let t = *self
+            .expectations
+            .get(self.index)
+            .unwrap_or_else(|| panic!("unexpected SPI transaction, all expectations consumed"));
+        self.index += 1;
+        t
+    }
+}
+
+impl<'a> Flush for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+impl<'a> Transfer<u8> for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_transfer<'w>(&mut self, words: &'w mut [u8]) -> Result<&'w [u8], Self::Error> {
+        match self.next() {
+            Transaction::Transfer(expected, response) => {
+                assert_eq!(&*words, expected, "SPI transfer data mismatch");
+                words.copy_from_slice(response);
+            }
+            t => panic!("expected {:?}, got SPI transfer", t),
+        }
+
+        Ok(words)
+    }
+}
+
+impl<'a> Write<u8> for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        match self.next() {
+            Transaction::Write(expected) => assert_eq!(words, expected, "SPI write data mismatch"),
+            t => panic!("expected {:?}, got SPI write", t),
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> WriteIter<u8> for Mock<'a> {
+    type Error = Infallible;
+
+    fn try_write_iter<WI>(&mut self, words: WI) -> Result<(), Self::Error>
+    where
+        WI: IntoIterator<Item = u8>,
+    {
+        match self.next() {
+            Transaction::Write(expected) => {
+                let mut count = 0;
+                for word in words {
+                    assert_eq!(
+                        Some(&word),
+                        expected.get(count),
+                        "SPI write data mismatch at word {}",
+                        count
+                    );
+                    count += 1;
+                }
+                assert_eq!(count, expected.len(), "SPI write data too short");
+            }
+            t => panic!("expected {:?}, got SPI write", t),
+        }
+
+        Ok(())
+    }
+}
@@ -7,31 +7,88 @@ pub use crate::adc::Channel as _embedded_hal_adc_Channel;
 pub use crate::adc::OneShot as _embedded_hal_adc_OneShot;
 pub use crate::blocking::delay::DelayMs as _embedded_hal_blocking_delay_DelayMs;
 pub use crate::blocking::delay::DelayUs as _embedded_hal_blocking_delay_DelayUs;
+pub use crate::blocking::i2c::scan::Scan as _embedded_hal_blocking_i2c_scan_Scan;
 pub use crate::blocking::i2c::{
-    Read as _embedded_hal_blocking_i2c_Read,
+    BusClear as _embedded_hal_blocking_i2c_BusClear,
+    GeneralCall as _embedded_hal_blocking_i2c_GeneralCall, Read as _embedded_hal_blocking_i2c_Read,
     Transactional as _embedded_hal_blocking_i2c_Transactional,
+    TransactionalIter as _embedded_hal_blocking_i2c_TransactionalIter,
     Write as _embedded_hal_blocking_i2c_Write, WriteIter as _embedded_hal_blocking_i2c_WriteIter,
     WriteIterRead as _embedded_hal_blocking_i2c_WriteIterRead,
     WriteRead as _embedded_hal_blocking_i2c_WriteRead,
 };
+pub use crate::blocking::qspi::{
+    Read as _embedded_hal_blocking_qspi_Read, Write as _embedded_hal_blocking_qspi_Write,
+};
+pub use crate::blocking::register::{
+    RegisterRead as _embedded_hal_blocking_register_RegisterRead,
+    RegisterWrite as _embedded_hal_blocking_register_RegisterWrite,
+};
 pub use crate::blocking::rng::Read as _embedded_hal_blocking_rng_Read;
+pub use crate::blocking::serial::Read as _embedded_hal_blocking_serial_Read;
+pub use crate::blocking::serial::ReadUntil as _embedded_hal_blocking_serial_ReadUntil;
 pub use crate::blocking::serial::Write as _embedded_hal_blocking_serial_Write;
+pub use crate::blocking::smbus::{
+    Block as _embedded_hal_blocking_smbus_Block, Byte as _embedded_hal_blocking_smbus_Byte,
+    ByteData as _embedded_hal_blocking_smbus_ByteData,
+    QuickCommand as _embedded_hal_blocking_smbus_QuickCommand,
+    WordData as _embedded_hal_blocking_smbus_WordData,
+};
 pub use crate::blocking::spi::{
+    Flush as _embedded_hal_blocking_spi_Flush, ThreeWire as _embedded_hal_blocking_spi_ThreeWire,
     Transfer as _embedded_hal_blocking_spi_Transfer, Write as _embedded_hal_blocking_spi_Write,
     WriteIter as _embedded_hal_blocking_spi_WriteIter,
 };
 pub use crate::capture::Capture as _embedded_hal_Capture;
+pub use crate::counter::PulseCounter as _embedded_hal_PulseCounter;
+pub use crate::digital::expander::PortExpander as _embedded_hal_digital_expander_PortExpander;
+pub use crate::digital::DriveConfig as _embedded_hal_digital_DriveConfig;
+pub use crate::digital::DriveModeConfig as _embedded_hal_digital_DriveModeConfig;
+pub use crate::digital::InputFilterConfig as _embedded_hal_digital_InputFilterConfig;
 pub use crate::digital::InputPin as _embedded_hal_digital_InputPin;
+pub use crate::digital::InputPort as _embedded_hal_digital_InputPort;
+pub use crate::digital::InterruptPin as _embedded_hal_digital_InterruptPin;
+pub use crate::digital::IoPin as _embedded_hal_digital_IoPin;
 pub use crate::digital::OutputPin as _embedded_hal_digital_OutputPin;
+pub use crate::digital::OutputPort as _embedded_hal_digital_OutputPort;
+pub use crate::digital::PullConfig as _embedded_hal_digital_PullConfig;
+pub use crate::digital::PulsePin as _embedded_hal_digital_PulsePin;
 pub use crate::digital::StatefulOutputPin as _embedded_hal_digital_StatefulOutputPin;
 pub use crate::digital::ToggleableOutputPin as _embedded_hal_digital_ToggleableOutputPin;
+pub use crate::digital::TriStatePin as _embedded_hal_digital_TriStatePin;
+pub use crate::digital::WakeupPin as _embedded_hal_digital_WakeupPin;
+pub use crate::i2c::dma::{
+    ReadStart as _embedded_hal_i2c_dma_ReadStart,
+    TransferWait as _embedded_hal_i2c_dma_TransferWait,
+    WriteReadStart as _embedded_hal_i2c_dma_WriteReadStart,
+    WriteStart as _embedded_hal_i2c_dma_WriteStart,
+};
+pub use crate::i2c::Configure as _embedded_hal_i2c_Configure;
+pub use crate::i2c::Target as _embedded_hal_i2c_Target;
 pub use crate::pwm::Pwm as _embedded_hal_Pwm;
 pub use crate::pwm::PwmPin as _embedded_hal_PwmPin;
 pub use crate::qei::Qei as _embedded_hal_Qei;
 pub use crate::rng::Read as _embedded_hal_rng_Read;
+pub use crate::serial::dma::{
+    CircularRead as _embedded_hal_serial_dma_CircularRead,
+    CircularReadStart as _embedded_hal_serial_dma_CircularReadStart,
+    WriteStart as _embedded_hal_serial_dma_WriteStart,
+    WriteWait as _embedded_hal_serial_dma_WriteWait,
+};
+pub use crate::serial::Break as _embedded_hal_serial_Break;
+pub use crate::serial::Configure as _embedded_hal_serial_Configure;
+pub use crate::serial::FlowControl as _embedded_hal_serial_FlowControl;
+pub use crate::serial::HalfDuplex as _embedded_hal_serial_HalfDuplex;
+pub use crate::serial::IdleLine as _embedded_hal_serial_IdleLine;
 pub use crate::serial::Read as _embedded_hal_serial_Read;
+pub use crate::serial::Split as _embedded_hal_serial_Split;
 pub use crate::serial::Write as _embedded_hal_serial_Write;
+pub use crate::spi::dma::{
+    TransferStart as _embedded_hal_spi_dma_TransferStart,
+    TransferWait as _embedded_hal_spi_dma_TransferWait,
+};
 pub use crate::spi::FullDuplex as _embedded_hal_spi_FullDuplex;
+pub use crate::spi::HalfDuplex as _embedded_hal_spi_HalfDuplex;
 pub use crate::timer::Cancel as _embedded_hal_timer_Cancel;
 pub use crate::timer::CountDown as _embedded_hal_timer_CountDown;
 pub use crate::timer::Periodic as _embedded_hal_timer_Periodic;
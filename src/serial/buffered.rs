@@ -0,0 +1,205 @@
+//! Interrupt fed receive buffering
+//!
+//! Polling a serial interface from the main loop drops words whenever they arrive faster than
+//! the loop comes around, as most peripherals only hold a word or two. A [`BufferedSerial`]
+//! stores the received bytes in a ring buffer instead: it is split into a [`Filler`], whose
+//! [`fill`](Filler::fill) method is called from the receive interrupt handler to move the
+//! received bytes into the buffer, and a [`Reader`] implementing the read traits on top of the
+//! buffer for the task side.
+//!
+//! The buffer is lock free, with a single producer and a single consumer. It only needs atomic
+//! loads and stores, which are available on all targets, including `thumbv6m`.
+//!
+//! ## Examples
+//!
+//! ```
+//! use embedded_hal::serial::buffered::BufferedSerial;
+//! use embedded_hal::serial::Read;
+//! # use core::convert::Infallible;
+//! # struct Uart(core::ops::Range<u8>);
+//! # impl Read<u8> for Uart {
+//! #     type Error = Infallible;
+//! #     fn try_read(&mut self) -> nb::Result<u8, Self::Error> {
+//! #         self.0.next().ok_or(nb::Error::WouldBlock)
+//! #     }
+//! # }
+//! # let uart = Uart(1..4);
+//!
+//! let mut serial = BufferedSerial::<_, 64>::new(uart);
+//! let (mut filler, mut reader) = serial.split();
+//!
+//! // in the receive interrupt handler
+//! filler.fill().unwrap();
+//!
+//! // in the main loop
+//! assert_eq!(reader.try_read(), Ok(1));
+//! assert_eq!(reader.len(), 2);
+//! ```
+
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use super::{Error, ErrorKind, Read};
+use crate::blocking::serial::read;
+
+/// Serial interface with a receive ring buffer of `N` bytes
+///
+/// The buffer holds up to `N - 1` bytes, as one slot is kept free to tell a full buffer from an
+/// empty one.
+pub struct BufferedSerial<S, const N: usize> {
+    serial: UnsafeCell<S>,
+    buffer: UnsafeCell<[u8; N]>,
+    /// Index of the next byte to read, only written by the reader
+    head: AtomicUsize,
+    /// Index of the next byte to store, only written by the filler
+    tail: AtomicUsize,
+    /// Set when a received byte was dropped because the buffer was full
+    overflow: AtomicBool,
+}
+
+// The filler and the reader only access their own end of the buffer, and the serial interface
+// is only accessed by the filler.
+unsafe impl<S, const N: usize> Sync for BufferedSerial<S, N> where S: Send {}
+
+impl<S, const N: usize> BufferedSerial<S, N> {
+    /// Creates a new buffered serial interface with an empty buffer
+    pub const fn new(serial: S) -> Self {
+        Self {
+            serial: UnsafeCell::new(serial),
+            buffer: UnsafeCell::new([0; N]),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            overflow: AtomicBool::new(false),
+        }
+    }
+
+    /// Splits the interface into its interrupt side and task side halves
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is smaller than 2, as such a buffer can't hold any byte.
+    pub fn split(&mut self) -> (Filler<'_, S, N>, Reader<'_, S, N>) {
+        assert!(N >= 2, "buffer too small");
+        (Filler { shared: self }, Reader { shared: self })
+    }
+
+    /// Destroys the buffered serial interface, returning the serial interface
+    ///
+    /// Bytes received but not read yet are discarded.
+    pub fn destroy(self) -> S {
+        self.serial.into_inner()
+    }
+}
+
+/// Interrupt side of a [`BufferedSerial`]
+pub struct Filler<'a, S, const N: usize> {
+    shared: &'a BufferedSerial<S, N>,
+}
+
+impl<'a, S, const N: usize> Filler<'a, S, N>
+where
+    S: Read<u8>,
+{
+    /// Moves all the bytes received by the serial interface into the buffer
+    ///
+    /// Bytes received while the buffer is full are dropped, which is reported by the next read
+    /// of the [`Reader`]. Reading stops at the first error of the serial interface.
+    pub fn fill(&mut self) -> Result<(), S::Error> {
+        // only the filler accesses the serial interface
+        let serial = unsafe { &mut *self.shared.serial.get() };
+        let buffer = self.shared.buffer.get() as *mut u8;
+
+        let mut tail = self.shared.tail.load(Ordering::Relaxed);
+        loop {
+            let byte = match serial.try_read() {
+                Ok(byte) => byte,
+                Err(nb::Error::WouldBlock) => return Ok(()),
+                Err(nb::Error::Other(e)) => return Err(e),
+            };
+
+            let next = (tail + 1) % N;
+            if next == self.shared.head.load(Ordering::Acquire) {
+                self.shared.overflow.store(true, Ordering::Relaxed);
+                continue;
+            }
+
+            // the slot at `tail` isn't readable until `tail` is published below
+            unsafe { buffer.add(tail).write(byte) };
+            tail = next;
+            self.shared.tail.store(tail, Ordering::Release);
+        }
+    }
+}
+
+/// Task side of a [`BufferedSerial`]
+pub struct Reader<'a, S, const N: usize> {
+    shared: &'a BufferedSerial<S, N>,
+}
+
+impl<'a, S, const N: usize> Reader<'a, S, N> {
+    /// Returns the number of bytes in the buffer
+    pub fn len(&self) -> usize {
+        let head = self.shared.head.load(Ordering::Relaxed);
+        let tail = self.shared.tail.load(Ordering::Acquire);
+        (tail + N - head) % N
+    }
+
+    /// Returns `true` if the buffer is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Buffered read error
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BufferedError {
+    /// Received bytes were dropped because the buffer was full
+    Overflow,
+}
+
+impl Error for BufferedError {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            BufferedError::Overflow => ErrorKind::Overrun,
+        }
+    }
+}
+
+impl core::fmt::Display for BufferedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BufferedError::Overflow => write!(f, "receive buffer overflow"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferedError {}
+
+impl<'a, S, const N: usize> Read<u8> for Reader<'a, S, N> {
+    type Error = BufferedError;
+
+    /// Reads the oldest byte of the buffer
+    ///
+    /// Returns an overflow error once after bytes were dropped, then the bytes that were kept.
+    fn try_read(&mut self) -> nb::Result<u8, Self::Error> {
+        // only the reader clears the flag, so a concurrent overflow is reported with this one
+        if self.shared.overflow.load(Ordering::Relaxed) {
+            self.shared.overflow.store(false, Ordering::Relaxed);
+            return Err(nb::Error::Other(BufferedError::Overflow));
+        }
+
+        let head = self.shared.head.load(Ordering::Relaxed);
+        if head == self.shared.tail.load(Ordering::Acquire) {
+            return Err(nb::Error::WouldBlock);
+        }
+
+        // the filler doesn't write the slot at `head` until it is released below
+        let byte = unsafe { (self.shared.buffer.get() as *const u8).add(head).read() };
+        self.shared.head.store((head + 1) % N, Ordering::Release);
+
+        Ok(byte)
+    }
+}
+
+impl<'a, S, const N: usize> read::Default<u8> for Reader<'a, S, N> {}
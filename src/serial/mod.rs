@@ -0,0 +1,586 @@
+//! Serial interface
+
+use nb;
+
+pub mod buffered;
+
+/// Serial error
+///
+/// Implemented by the error types of serial interfaces so protocol layers can react to specific
+/// failures without knowing the concrete error type, e.g. to resynchronize after a corrupted
+/// word instead of giving up:
+///
+/// ```
+/// use embedded_hal::serial::{Error, ErrorKind, Read};
+///
+/// /// Waits for the start of the next frame, skipping corrupted words
+/// fn sync<S>(port: &mut S, start: u8) -> nb::Result<(), S::Error>
+/// where
+///     S: Read<u8>,
+///     S::Error: Error,
+/// {
+///     loop {
+///         match nb::block!(port.try_read()) {
+///             Ok(word) if word == start => return Ok(()),
+///             Ok(_) => {}
+///             Err(e) if e.kind() == ErrorKind::Other => return Err(nb::Error::Other(e)),
+///             Err(_) => {}
+///         }
+///     }
+/// }
+/// ```
+pub trait Error: core::fmt::Debug {
+    /// Converts the error to a generic serial error kind
+    ///
+    /// Implementations should return `ErrorKind::Other` for errors that don't match any of the
+    /// generic kinds.
+    fn kind(&self) -> ErrorKind;
+}
+
+impl Error for core::convert::Infallible {
+    fn kind(&self) -> ErrorKind {
+        match *self {}
+    }
+}
+
+/// Generic serial error kind
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// A word was received before the previously received word was read
+    Overrun,
+    /// The stop bit of a word was not detected
+    FrameFormat,
+    /// The parity bit of a word didn't match its data
+    Parity,
+    /// Noise was detected while receiving a word
+    Noise,
+    /// A different error occurred
+    Other,
+}
+
+impl core::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ErrorKind::Overrun => write!(f, "received data was overwritten before being read"),
+            ErrorKind::FrameFormat => write!(f, "the stop bit was not detected"),
+            ErrorKind::Parity => write!(f, "the parity check failed"),
+            ErrorKind::Noise => write!(f, "noise was detected on the line"),
+            ErrorKind::Other => write!(f, "an unspecified error occurred"),
+        }
+    }
+}
+
+/// Read half of a serial interface
+///
+/// Some serial interfaces support different data sizes (8 bits, 9 bits, etc.);
+/// This can be encoded in this trait via the `Word` type parameter.
+pub trait Read<Word> {
+    /// Read error
+    type Error;
+
+    /// Reads a single word from the serial interface
+    fn try_read(&mut self) -> nb::Result<Word, Self::Error>;
+}
+
+/// Write half of a serial interface
+pub trait Write<Word> {
+    /// Write error
+    type Error;
+
+    /// Writes a single word to the serial interface
+    fn try_write(&mut self, word: Word) -> nb::Result<(), Self::Error>;
+
+    /// Ensures that none of the previously written words are still buffered
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error>;
+}
+
+/// Direction of the data line of a half duplex serial interface
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// The interface drives the data line
+    Transmit,
+    /// The other end drives the data line
+    Receive,
+}
+
+/// Half duplex serial interface (single wire)
+///
+/// Transmission and reception share a single data line, as used by smart servos like Dynamixel
+/// or some sensors, so words are either sent or received but never both at the same time.
+///
+/// ```
+/// use embedded_hal::serial::{Direction, HalfDuplex};
+///
+/// /// Pings the Dynamixel servo `id`, returning the first byte of its status packet
+/// fn ping<S: HalfDuplex>(servo: &mut S, id: u8) -> nb::Result<u8, S::Error> {
+///     nb::block!(servo.try_set_direction(Direction::Transmit))?;
+///     for &word in &[0xFF, 0xFF, id, 0x02, 0x01, !(id.wrapping_add(0x03))] {
+///         nb::block!(servo.try_write(word))?;
+///     }
+///     // waits for the checksum to be sent before releasing the line
+///     nb::block!(servo.try_set_direction(Direction::Receive))?;
+///     servo.try_read()
+/// }
+/// ```
+///
+/// # Notes
+///
+/// - `try_write` must only be called while the direction is `Direction::Transmit` and
+///   `try_read` must only be called while the direction is `Direction::Receive`.
+///   Implementations are suggested to return an error otherwise.
+///
+/// - The receiver is disabled while transmitting, so the words sent are not read back.
+pub trait HalfDuplex<Word = u8> {
+    /// Error type
+    type Error;
+
+    /// Switches the direction of the data line
+    ///
+    /// This returns `WouldBlock` until the previously written words have been sent, then
+    /// releases or takes over the line, so the other end can start answering as soon as this
+    /// succeeds.
+    fn try_set_direction(&mut self, direction: Direction) -> nb::Result<(), Self::Error>;
+
+    /// Writes a single word to the serial interface
+    fn try_write(&mut self, word: Word) -> nb::Result<(), Self::Error>;
+
+    /// Reads a single word from the serial interface
+    fn try_read(&mut self) -> nb::Result<Word, Self::Error>;
+
+    /// Ensures that none of the previously written words are still buffered
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error>;
+}
+
+/// Splitting of a serial interface into independent halves
+///
+/// The halves can be moved to different tasks or interrupt handlers, e.g. to receive words in
+/// the interrupt handler of the receiver while the main loop transmits.
+///
+/// ```
+/// use embedded_hal::serial::{Read, Split, Write};
+///
+/// fn echo<S: Split>(serial: S) {
+///     let (mut tx, mut rx) = serial.split();
+///     // `rx` could be handed to an interrupt handler instead
+///     if let Ok(word) = rx.try_read() {
+///         let _ = tx.try_write(word);
+///     }
+/// }
+/// ```
+pub trait Split<Word = u8> {
+    /// Transmit half
+    type Tx: Write<Word>;
+
+    /// Receive half
+    type Rx: Read<Word>;
+
+    /// Splits the interface into its transmit and receive halves
+    fn split(self) -> (Self::Tx, Self::Rx);
+}
+
+/// Parity bit
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parity {
+    /// No parity bit
+    None,
+    /// Parity bit making the number of set bits even
+    Even,
+    /// Parity bit making the number of set bits odd
+    Odd,
+    /// Parity bit always set
+    Mark,
+    /// Parity bit always cleared
+    Space,
+}
+
+/// Number of stop bits
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StopBits {
+    /// One stop bit
+    One,
+    /// One and a half stop bits
+    OnePointFive,
+    /// Two stop bits
+    Two,
+}
+
+/// Number of data bits per word
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WordLength {
+    /// 5 data bits
+    Five,
+    /// 6 data bits
+    Six,
+    /// 7 data bits
+    Seven,
+    /// 8 data bits
+    Eight,
+    /// 9 data bits
+    Nine,
+}
+
+/// Runtime configuration of a serial interface
+///
+/// The new settings apply to the following words; call `try_flush` first to make sure the
+/// previously written words are sent with the old settings. Implementations return an error for
+/// settings they don't support.
+///
+/// ```
+/// use embedded_hal::serial::{Configure, Parity, StopBits, Write};
+///
+/// /// Sends a DMX512 frame at 250 kbaud, then switches back to the console settings
+/// fn send_dmx<S, E>(port: &mut S, frame: &[u8]) -> nb::Result<(), E>
+/// where
+///     S: Configure<Error = E> + Write<u8, Error = E>,
+/// {
+///     nb::block!(port.try_flush())?;
+///     port.try_set_baud_rate(250_000)?;
+///     port.try_set_stop_bits(StopBits::Two)?;
+///     for byte in frame {
+///         nb::block!(port.try_write(*byte))?;
+///     }
+///     nb::block!(port.try_flush())?;
+///     port.try_set_baud_rate(115_200)?;
+///     port.try_set_stop_bits(StopBits::One)?;
+///
+///     Ok(())
+/// }
+/// ```
+pub trait Configure {
+    /// Error type
+    type Error;
+
+    /// Sets the baud rate to `baud`
+    ///
+    /// Implementations use the closest baud rate they support, and return an error if it is too
+    /// far off for reliable communication.
+    fn try_set_baud_rate(&mut self, baud: u32) -> Result<(), Self::Error>;
+
+    /// Sets the parity bit
+    fn try_set_parity(&mut self, parity: Parity) -> Result<(), Self::Error>;
+
+    /// Sets the number of stop bits
+    fn try_set_stop_bits(&mut self, stop_bits: StopBits) -> Result<(), Self::Error>;
+
+    /// Sets the number of data bits per word
+    fn try_set_word_length(&mut self, length: WordLength) -> Result<(), Self::Error>;
+}
+
+/// Hardware flow control of a serial interface
+///
+/// With flow control enabled, the interface deasserts RTS when it can't receive more words and
+/// only transmits while CTS is asserted. With it disabled, RTS can be driven manually, e.g. by
+/// modems using it as a wake up signal.
+///
+/// ```
+/// use embedded_hal::serial::FlowControl;
+///
+/// /// Wakes the modem up, then hands RTS over to the hardware
+/// fn wake<S: FlowControl>(modem: &mut S) -> Result<(), S::Error> {
+///     modem.try_disable_flow_control()?;
+///     modem.try_set_rts(true)?;
+///     modem.try_enable_flow_control()
+/// }
+/// ```
+pub trait FlowControl {
+    /// Error type
+    type Error;
+
+    /// Enables RTS/CTS flow control
+    fn try_enable_flow_control(&mut self) -> Result<(), Self::Error>;
+
+    /// Disables RTS/CTS flow control
+    fn try_disable_flow_control(&mut self) -> Result<(), Self::Error>;
+
+    /// Asserts RTS if `asserted` is `true`, deasserts it otherwise
+    ///
+    /// This returns an error while flow control is enabled.
+    fn try_set_rts(&mut self, asserted: bool) -> Result<(), Self::Error>;
+
+    /// Is CTS asserted?
+    fn try_is_cts_asserted(&self) -> Result<bool, Self::Error>;
+}
+
+/// Break conditions of a serial interface
+///
+/// A break holds the line low for longer than a word, and marks the start of frames in protocols
+/// like LIN and DMX512.
+///
+/// ```
+/// use embedded_hal::serial::{Break, Write};
+///
+/// /// Starts a DMX512 frame at 250 kbaud
+/// fn start_frame<S, E>(port: &mut S) -> nb::Result<(), E>
+/// where
+///     S: Break<Error = E> + Write<u8, Error = E>,
+/// {
+///     // at least 88 us
+///     nb::block!(port.try_send_break(22))?;
+///     // start code
+///     port.try_write(0x00)
+/// }
+/// ```
+pub trait Break {
+    /// Error type
+    type Error;
+
+    /// Sends a break lasting at least `bits` bit times
+    ///
+    /// This returns `WouldBlock` until the previously written words have been sent and the break
+    /// has been started.
+    fn try_send_break(&mut self, bits: u32) -> nb::Result<(), Self::Error>;
+
+    /// Was a break received since the last call?
+    fn try_take_break(&mut self) -> Result<bool, Self::Error>;
+}
+
+/// Idle line detection of a serial receiver
+///
+/// The line is idle when no start bit has been received for at least one frame time. Protocols
+/// like Modbus RTU delimit variable length packets with such gaps instead of length fields or
+/// terminators.
+///
+/// ```
+/// use embedded_hal::serial::{IdleLine, Read};
+///
+/// /// Receives a Modbus RTU frame into `frame`, returning its length
+/// ///
+/// /// Words beyond the length of `frame` are dropped.
+/// fn receive_frame<S, E>(port: &mut S, frame: &mut [u8]) -> Result<usize, E>
+/// where
+///     S: IdleLine<Error = E> + Read<u8, Error = E>,
+/// {
+///     let mut len = 0;
+///     loop {
+///         match port.try_read() {
+///             Ok(word) => {
+///                 if let Some(slot) = frame.get_mut(len) {
+///                     *slot = word;
+///                     len += 1;
+///                 }
+///             }
+///             Err(nb::Error::WouldBlock) => match port.try_wait_idle() {
+///                 Ok(()) if len > 0 => return Ok(len),
+///                 Ok(()) | Err(nb::Error::WouldBlock) => {}
+///                 Err(nb::Error::Other(e)) => return Err(e),
+///             },
+///             Err(nb::Error::Other(e)) => return Err(e),
+///         }
+///     }
+/// }
+/// ```
+pub trait IdleLine {
+    /// Error type
+    type Error;
+
+    /// Waits until the line goes idle after receiving a word
+    ///
+    /// This returns `WouldBlock` until the receiver has detected an idle line following the
+    /// last received word. Each idle condition is reported once, so the next call waits for
+    /// another word to be received and the line to go idle again.
+    fn try_wait_idle(&mut self) -> nb::Result<(), Self::Error>;
+
+    /// Is the line currently idle?
+    fn try_is_idle(&self) -> Result<bool, Self::Error>;
+}
+
+/// DMA backed transfers
+///
+/// Streams at high baud rates, e.g. from GPS receivers or cellular modems, can't be serviced
+/// one word at a time through `nb`. With these traits the peripheral moves the data while the
+/// CPU does other work. Buffers are required to be `'static` as the DMA engine keeps accessing
+/// them after the transfer starts; ownership is handed back once the transfer is complete or
+/// stopped, along with the error if it failed, or with the error of the start method if the
+/// transfer couldn't be started.
+///
+/// Transmission sends a single buffer, split into [`WriteStart`](dma::WriteStart) and
+/// [`WriteWait`](dma::WriteWait). Reception is continuous instead: the DMA engine writes the
+/// received words into a circular buffer, wrapping around at its end, and
+/// [`CircularRead::try_read_available`](dma::CircularRead::try_read_available) copies out the
+/// words received since the previous call.
+///
+/// # Notes
+///
+/// - Only one transmission and one reception may be in progress at a time. Implementations are
+///   suggested to return an error from the start methods if one is already in progress.
+///
+/// - Received words are lost if they aren't read out before the DMA engine wraps around to
+///   their position again. Implementations are suggested to report this with an overrun error.
+pub mod dma {
+    /// Start of a DMA transmission
+    pub trait WriteStart<Word: 'static> {
+        /// Error type
+        type Error;
+
+        /// Starts sending `words`
+        ///
+        /// On error, `words` is returned along with the error.
+        fn try_start_write(
+            &mut self,
+            words: &'static [Word],
+        ) -> Result<(), (Self::Error, &'static [Word])>;
+    }
+
+    /// Completion of a DMA transmission
+    pub trait WriteWait<Word: 'static> {
+        /// Error type
+        type Error;
+
+        /// Non-blockingly "waits" until the transmission started by `try_start_write` finishes,
+        /// returning its buffer
+        ///
+        /// The last words may still be in the transmit shift register; use `flush` from
+        /// [`Write`](super::Write) to wait until they have been sent. If the transmission failed,
+        /// the buffer is returned along with the error.
+        fn try_wait_write(&mut self)
+            -> nb::Result<&'static [Word], (Self::Error, &'static [Word])>;
+    }
+
+    /// Start of a continuous DMA reception
+    pub trait CircularReadStart<Word: 'static> {
+        /// Error type
+        type Error;
+
+        /// Starts receiving words into `buffer`, wrapping around at its end
+        ///
+        /// On error, `buffer` is returned along with the error.
+        fn try_start_circular_read(
+            &mut self,
+            buffer: &'static mut [Word],
+        ) -> Result<(), (Self::Error, &'static mut [Word])>;
+    }
+
+    /// Continuous DMA reception in progress
+    pub trait CircularRead<Word: 'static> {
+        /// Error type
+        type Error;
+
+        /// Moves the words received since the previous call into `words`, oldest first, and
+        /// returns how many were moved
+        ///
+        /// Returns 0 if no words have been received. Words that don't fit in `words` are kept
+        /// for the next call.
+        fn try_read_available(&mut self, words: &mut [Word]) -> Result<usize, Self::Error>;
+
+        /// Stops the reception, returning its buffer
+        ///
+        /// Words received but not read out yet are discarded. If the reception couldn't be
+        /// stopped cleanly, the buffer is returned along with the error.
+        fn try_stop_circular_read(
+            &mut self,
+        ) -> Result<&'static mut [Word], (Self::Error, &'static mut [Word])>;
+    }
+}
+
+/// 9-bit words
+///
+/// Serial interfaces supporting 9-bit words, configured with [`WordLength::Nine`], implement
+/// the serial traits for `u16` words holding the 9 bits in their lowest bits. The blocking
+/// traits and the wrappers of this crate support such words as well.
+///
+/// In multiprocessor protocols, like multi-drop RS-485 buses, the 9th bit marks the words
+/// holding the address of the node the following data words are meant for. The functions of this
+/// module build and decode such words, and [`ParityAddressing`](nine_bit::ParityAddressing)
+/// sends them over 8-bit interfaces by using the parity bit as 9th bit.
+pub mod nine_bit {
+    use super::{Configure, Parity, Write};
+
+    /// Bit marking address words
+    pub const ADDRESS_BIT: u16 = 1 << 8;
+
+    /// Returns the word selecting the node `address`
+    pub fn address(address: u8) -> u16 {
+        ADDRESS_BIT | u16::from(address)
+    }
+
+    /// Returns the data word holding `data`
+    pub fn data(data: u8) -> u16 {
+        u16::from(data)
+    }
+
+    /// Is `word` an address word?
+    pub fn is_address(word: u16) -> bool {
+        word & ADDRESS_BIT != 0
+    }
+
+    /// Returns the 8 bits of address or data of `word`
+    pub fn payload(word: u16) -> u8 {
+        word as u8
+    }
+
+    /// Adapter sending 9-bit words over an 8-bit serial interface
+    ///
+    /// The parity bit of the interface is used as 9th bit, switching to mark parity for address
+    /// words and to space parity for data words. The interface is flushed before switching, so
+    /// the throughput drops when switching often. Receiving 9-bit words this way isn't supported,
+    /// as the parity bit of the received words can't be read.
+    ///
+    /// ```
+    /// use embedded_hal::serial::nine_bit::{self, ParityAddressing};
+    /// use embedded_hal::serial::Write;
+    /// # use core::convert::Infallible;
+    /// # use embedded_hal::serial::{Configure, Parity, StopBits, WordLength};
+    /// # struct Uart;
+    /// # impl embedded_hal::serial::Write<u8> for Uart {
+    /// #     type Error = Infallible;
+    /// #     fn try_write(&mut self, _: u8) -> nb::Result<(), Self::Error> { Ok(()) }
+    /// #     fn try_flush(&mut self) -> nb::Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # impl Configure for Uart {
+    /// #     type Error = Infallible;
+    /// #     fn try_set_baud_rate(&mut self, _: u32) -> Result<(), Self::Error> { Ok(()) }
+    /// #     fn try_set_parity(&mut self, _: Parity) -> Result<(), Self::Error> { Ok(()) }
+    /// #     fn try_set_stop_bits(&mut self, _: StopBits) -> Result<(), Self::Error> { Ok(()) }
+    /// #     fn try_set_word_length(&mut self, _: WordLength) -> Result<(), Self::Error> { Ok(()) }
+    /// # }
+    /// # let uart = Uart;
+    ///
+    /// let mut bus = ParityAddressing::new(uart);
+    ///
+    /// nb::block!(bus.try_write(nine_bit::address(0x12))).unwrap();
+    /// for byte in b"ON" {
+    ///     nb::block!(bus.try_write(nine_bit::data(*byte))).unwrap();
+    /// }
+    /// ```
+    pub struct ParityAddressing<S> {
+        serial: S,
+        /// Whether the parity is set to mark, if known
+        mark: Option<bool>,
+    }
+
+    impl<S> ParityAddressing<S> {
+        /// Wraps the 8-bit serial interface `serial`
+        pub fn new(serial: S) -> Self {
+            Self { serial, mark: None }
+        }
+
+        /// Destroys the adapter, returning the serial interface
+        pub fn destroy(self) -> S {
+            self.serial
+        }
+    }
+
+    impl<S, E> Write<u16> for ParityAddressing<S>
+    where
+        S: Write<u8, Error = E> + Configure<Error = E>,
+    {
+        type Error = E;
+
+        fn try_write(&mut self, word: u16) -> nb::Result<(), Self::Error> {
+            let mark = is_address(word);
+            if self.mark != Some(mark) {
+                self.serial.try_flush()?;
+                self.serial
+                    .try_set_parity(if mark { Parity::Mark } else { Parity::Space })?;
+                self.mark = Some(mark);
+            }
+
+            self.serial.try_write(payload(word))
+        }
+
+        fn try_flush(&mut self) -> nb::Result<(), Self::Error> {
+            self.serial.try_flush()
+        }
+    }
+}
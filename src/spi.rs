@@ -2,6 +2,68 @@
 
 use nb;
 
+/// SPI error
+///
+/// Implemented by the error types of SPI implementations so generic drivers can react to
+/// specific failures without knowing the concrete error type, e.g.
+///
+/// ```
+/// use embedded_hal::blocking::spi::Write;
+/// use embedded_hal::spi::{Error, ErrorKind};
+///
+/// fn write_with_retry<S>(spi: &mut S, words: &[u8]) -> Result<(), S::Error>
+/// where
+///     S: Write<u8>,
+///     S::Error: Error,
+/// {
+///     match spi.try_write(words) {
+///         Err(e) if e.kind() == ErrorKind::Overrun => spi.try_write(words),
+///         res => res,
+///     }
+/// }
+/// ```
+pub trait Error: core::fmt::Debug {
+    /// Converts the error to a generic SPI error kind
+    ///
+    /// Implementations should return `ErrorKind::Other` for errors that don't match any of the
+    /// generic kinds.
+    fn kind(&self) -> ErrorKind;
+}
+
+impl Error for core::convert::Infallible {
+    fn kind(&self) -> ErrorKind {
+        match *self {}
+    }
+}
+
+/// Generic SPI error kind
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Data was received before the previously received data was read
+    Overrun,
+    /// Multiple devices on the bus attempted to drive it as master at the same time
+    ModeFault,
+    /// Received data did not conform to the configured frame format
+    FrameFormat,
+    /// The chip select line could not be asserted or released
+    ChipSelectFault,
+    /// A different error occurred
+    Other,
+}
+
+impl core::fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ErrorKind::Overrun => write!(f, "received data was overwritten before being read"),
+            ErrorKind::ModeFault => write!(f, "multiple masters attempted to drive the bus"),
+            ErrorKind::FrameFormat => write!(f, "received data has an invalid frame format"),
+            ErrorKind::ChipSelectFault => write!(f, "chip select could not be set"),
+            ErrorKind::Other => write!(f, "an unspecified error occurred"),
+        }
+    }
+}
+
 /// Full duplex (master mode)
 ///
 /// # Notes
@@ -11,13 +73,13 @@ use nb;
 /// - Due to how full duplex SPI works each `try_read` call must be preceded by a `try_send` call.
 ///
 /// - `try_read` calls only return the data received with the last `try_send` call.
-/// Previously received data is discarded
+///   Previously received data is discarded
 ///
 /// - Data is only guaranteed to be clocked out when the `try_read` call succeeds.
-/// The slave select line shouldn't be released before that.
+///   The slave select line shouldn't be released before that.
 ///
 /// - Some SPIs can work with 8-bit *and* 16-bit words. You can overload this trait with different
-/// `Word` types to allow operation in both modes.
+///   `Word` types to allow operation in both modes.
 pub trait FullDuplex<Word> {
     /// An enumeration of SPI errors
     type Error;
@@ -32,6 +94,53 @@ pub trait FullDuplex<Word> {
     fn try_send(&mut self, word: Word) -> nb::Result<(), Self::Error>;
 }
 
+/// Direction of the bidirectional data line of a half duplex SPI
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// The master drives the data line
+    Transmit,
+    /// The slave drives the data line
+    Receive,
+}
+
+/// Half duplex (master mode, 3-wire)
+///
+/// The MOSI line is used bidirectionally and no MISO line is present, so data is either sent to
+/// or received from the slave but never both at the same time.
+///
+/// # Notes
+///
+/// - It's the task of the user of this interface to manage the slave select lines
+///
+/// - `try_send` must only be called while the direction is `Direction::Transmit` and `try_read`
+///   must only be called while the direction is `Direction::Receive`. Implementations are
+///   suggested to return an error otherwise.
+///
+/// - In `Direction::Receive` each `try_read` call clocks a single word in from the slave.
+///
+/// - Data is only guaranteed to be clocked out once `try_set_direction` succeeds, or in the case
+///   of the last word of a transaction, once `try_flush` succeeds. The slave select line shouldn't
+///   be released before that.
+pub trait HalfDuplex<Word> {
+    /// An enumeration of SPI errors
+    type Error;
+
+    /// Switches the direction of the data line
+    ///
+    /// This waits for any word currently in the shift register to be clocked out before the
+    /// direction is changed.
+    fn try_set_direction(&mut self, direction: Direction) -> nb::Result<(), Self::Error>;
+
+    /// Sends a word to the slave
+    fn try_send(&mut self, word: Word) -> nb::Result<(), Self::Error>;
+
+    /// Clocks a word in from the slave
+    fn try_read(&mut self) -> nb::Result<Word, Self::Error>;
+
+    /// Ensures that none of the previously sent words are still in the shift register
+    fn try_flush(&mut self) -> nb::Result<(), Self::Error>;
+}
+
 /// Clock polarity
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Polarity {
@@ -82,3 +191,49 @@ pub const MODE_3: Mode = Mode {
     polarity: Polarity::IdleHigh,
     phase: Phase::CaptureOnSecondTransition,
 };
+
+/// DMA backed (master mode) transfers
+///
+/// A transfer is split into a starting and a completion step so the CPU is free to do other
+/// work while the peripheral moves the data. Buffers are required to be `'static` as the DMA
+/// engine keeps accessing them after `try_start_transfer` returns; ownership is handed back by
+/// `try_wait` once the transfer is complete, or with the error of `try_start_transfer` or
+/// `try_wait` if the transfer couldn't be started or failed.
+///
+/// # Notes
+///
+/// - It's the task of the user of this interface to manage the slave select lines. The slave
+///   select line shouldn't be released before `try_wait` returns the buffer.
+///
+/// - Only one transfer may be in progress at a time. Implementations are suggested to return an
+///   error from `try_start_transfer` if a transfer is already in progress.
+pub mod dma {
+    /// Start of a DMA transfer
+    pub trait TransferStart<Word: 'static> {
+        /// An enumeration of SPI errors
+        type Error;
+
+        /// Starts sending `words` to the slave, replacing them in place with the words received
+        /// from the slave
+        ///
+        /// On error, `words` is returned along with the error.
+        fn try_start_transfer(
+            &mut self,
+            words: &'static mut [Word],
+        ) -> Result<(), (Self::Error, &'static mut [Word])>;
+    }
+
+    /// Completion of a DMA transfer
+    pub trait TransferWait<Word: 'static> {
+        /// An enumeration of SPI errors
+        type Error;
+
+        /// Non-blockingly "waits" until the transfer started by `try_start_transfer` finishes
+        ///
+        /// Returns the buffer, now holding the words received from the slave. If the transfer
+        /// failed, the buffer is returned along with the error.
+        fn try_wait(
+            &mut self,
+        ) -> nb::Result<&'static mut [Word], (Self::Error, &'static mut [Word])>;
+    }
+}